@@ -0,0 +1,154 @@
+use crate::days;
+use crate::error::{AocError, Result};
+use crate::input::InputSource;
+use crate::runner::{self, Job};
+use crate::solution::Solver;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Every subdirectory of `dir` is one account, holding its inputs in
+/// `<year>/day-N.txt` like the input cache. Inputs of the first year may
+/// also sit directly in the account as `day-N.txt`.
+pub fn discover(dir: &Path) -> Result<Vec<(String, PathBuf)>> {
+    let entries = fs::read_dir(dir).map_err(|error| AocError::io(dir.display(), error))?;
+    let mut accounts: Vec<(String, PathBuf)> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .filter_map(|path| {
+            let name = path.file_name()?.to_str()?.to_string();
+            Some((name, path))
+        })
+        .collect();
+    if accounts.is_empty() {
+        return Err(AocError::validation(format!(
+            "No account directories found in {}",
+            dir.display()
+        )));
+    }
+    accounts.sort();
+    Ok(accounts)
+}
+
+// Accounts were laid out without year directories while there only was the
+// first year, so those inputs still count for it
+fn input_path(dir: &Path, year: u32, day: u32) -> Option<PathBuf> {
+    let name = format!("day-{}.txt", day);
+    let path = dir.join(year.to_string()).join(&name);
+    if path.is_file() {
+        return Some(path);
+    }
+    let flat = dir.join(name);
+    if year == days::first_year() && flat.is_file() {
+        return Some(flat);
+    }
+    None
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Cell {
+    Answer(String),
+    /// The account has no input for the day
+    Missing,
+    Failed(String),
+}
+
+/// Answers of every account, per day and part
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    pub accounts: Vec<String>,
+    pub cells: BTreeMap<(u32, u32), Vec<Cell>>,
+}
+
+/// Solves every part of every day of `year` against every account's input,
+/// on `threads` workers
+pub fn solve_matrix(
+    accounts: &[(String, PathBuf)],
+    year: u32,
+    solvers: &[&dyn Solver],
+    parts: &[u32],
+    threads: usize,
+) -> Matrix {
+    let mut cells = BTreeMap::new();
+    let mut jobs = Vec::new();
+    // Which cell each job answers
+    let mut targets = Vec::new();
+    for solver in solvers {
+        for part in parts {
+            cells.insert((solver.day(), *part), vec![Cell::Missing; accounts.len()]);
+        }
+        for (index, (_, dir)) in accounts.iter().enumerate() {
+            let path = match input_path(dir, year, solver.day()) {
+                Some(path) => path,
+                None => continue,
+            };
+            for part in parts {
+                jobs.push(Job {
+                    solver: *solver,
+                    source: InputSource::File(path.clone()),
+                    part: *part,
+                });
+                targets.push(index);
+            }
+        }
+    }
+    let reports = runner::solve_jobs(&jobs, threads);
+    for ((job, index), report) in jobs.iter().zip(targets).zip(reports) {
+        let cell = match report.outcome {
+            Ok(result) => Cell::Answer(result.answer),
+            Err(e) => Cell::Failed(e),
+        };
+        if let Some(row) = cells.get_mut(&(job.solver.day(), job.part)) {
+            row[index] = cell;
+        }
+    }
+    Matrix {
+        accounts: accounts.iter().map(|(name, _)| name.clone()).collect(),
+        cells,
+    }
+}
+
+/// One row per day and part, one column per account. Failures are listed
+/// below the table.
+pub fn render_matrix(matrix: &Matrix) -> String {
+    let width = matrix
+        .cells
+        .values()
+        .flatten()
+        .map(|cell| match cell {
+            Cell::Answer(answer) => answer.len(),
+            _ => 0,
+        })
+        .chain(matrix.accounts.iter().map(|name| name.len()))
+        .max()
+        .unwrap_or(0)
+        .max(6)
+        + 2;
+    let mut table = format!("{:<5}{:<6}", "Day", "Part");
+    for account in matrix.accounts.iter() {
+        table.push_str(&format!("{:>width$}", account, width = width));
+    }
+    table.push('\n');
+    let mut failures = String::new();
+    for ((day, part), row) in matrix.cells.iter() {
+        table.push_str(&format!("{:<5}{:<6}", day, part));
+        for (account, cell) in matrix.accounts.iter().zip(row) {
+            let text = match cell {
+                Cell::Answer(answer) => answer.as_str(),
+                Cell::Missing => "-",
+                Cell::Failed(e) => {
+                    failures.push_str(&format!(
+                        "Day {} part {} for {}: {}\n",
+                        day, part, account, e
+                    ));
+                    "failed"
+                }
+            };
+            table.push_str(&format!("{:>width$}", text, width = width));
+        }
+        table.push('\n');
+    }
+    table.push_str(&failures);
+    table
+}
@@ -0,0 +1,200 @@
+use crate::days;
+use crate::error::Result;
+use crate::memory::{self, format_bytes, AllocStats};
+use crate::solution::Solver;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    /// Untimed runs before measuring, to warm up caches and the allocator
+    pub warmup: usize,
+    pub iterations: usize,
+}
+
+impl Default for BenchConfig {
+    fn default() -> BenchConfig {
+        BenchConfig {
+            warmup: 2,
+            iterations: 10,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timings {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Timings {
+    fn from_samples(mut samples: Vec<Duration>) -> Timings {
+        if samples.is_empty() {
+            return Timings {
+                min: Duration::default(),
+                median: Duration::default(),
+                mean: Duration::default(),
+            };
+        }
+        samples.sort();
+        let total: Duration = samples.iter().sum();
+        Timings {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            mean: total / samples.len() as u32,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DayBenchmark {
+    pub day: u32,
+    pub parse: Timings,
+    pub part1: Timings,
+    pub part2: Timings,
+    /// Heap usage of parse, part 1 and part 2, with the `alloc-stats` feature
+    pub memory: Option<[AllocStats; 3]>,
+}
+
+impl DayBenchmark {
+    fn stages(&self) -> [(&'static str, &Timings); 3] {
+        [
+            ("parse", &self.parse),
+            ("part 1", &self.part1),
+            ("part 2", &self.part2),
+        ]
+    }
+}
+
+// One more untimed run of every stage, so counting does not skew the timings
+fn measure_memory(solver: &dyn Solver, raw_input: &str) -> Result<Option<[AllocStats; 3]>> {
+    let (input, parse) = memory::measure(|| solver.parse_input(raw_input));
+    let input = input?;
+    let (answer, part1) = memory::measure(|| solver.solve_part(input.as_ref(), 1));
+    answer?;
+    let (answer, part2) = memory::measure(|| solver.solve_part(input.as_ref(), 2));
+    answer?;
+    Ok(match (parse, part1, part2) {
+        (Some(parse), Some(part1), Some(part2)) => Some([parse, part1, part2]),
+        _ => None,
+    })
+}
+
+/// Times parsing and both parts of `solver` separately. Each part is timed
+/// against a single parsed input, so parsing is not counted twice.
+pub fn bench_solver(
+    solver: &dyn Solver,
+    raw_input: &str,
+    config: &BenchConfig,
+) -> Result<DayBenchmark> {
+    let parse = measure(config, || {
+        black_box(solver.parse_input(raw_input)?);
+        Ok(())
+    })?;
+    let input = solver.parse_input(raw_input)?;
+    let part1 = measure(config, || {
+        black_box(solver.solve_part(input.as_ref(), 1)?);
+        Ok(())
+    })?;
+    let part2 = measure(config, || {
+        black_box(solver.solve_part(input.as_ref(), 2)?);
+        Ok(())
+    })?;
+    Ok(DayBenchmark {
+        day: solver.day(),
+        parse,
+        part1,
+        part2,
+        memory: measure_memory(solver, raw_input)?,
+    })
+}
+
+fn measure<F: FnMut() -> Result<()>>(config: &BenchConfig, mut stage: F) -> Result<Timings> {
+    for _ in 0..config.warmup {
+        stage()?;
+    }
+    let mut samples = Vec::with_capacity(config.iterations);
+    for _ in 0..config.iterations {
+        let start = Instant::now();
+        stage()?;
+        samples.push(start.elapsed());
+    }
+    Ok(Timings::from_samples(samples))
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let micros = duration.as_secs_f64() * 1_000_000.0;
+    if micros < 1_000.0 {
+        format!("{:.1} µs", micros)
+    } else if micros < 1_000_000.0 {
+        format!("{:.2} ms", micros / 1_000.0)
+    } else {
+        format!("{:.2} s", micros / 1_000_000.0)
+    }
+}
+
+pub fn render_table(results: &[DayBenchmark]) -> String {
+    let with_memory = results.iter().any(|r| r.memory.is_some());
+    let mut table = format!(
+        "{:<5}{:<8}{:>12}{:>12}{:>12}",
+        "Day", "Stage", "Min", "Median", "Mean"
+    );
+    if with_memory {
+        table.push_str(&format!("{:>12}{:>10}", "Peak", "Allocs"));
+    }
+    table.push('\n');
+    for result in results {
+        for (i, (stage, timings)) in result.stages().iter().enumerate() {
+            table.push_str(&format!(
+                "{:<5}{:<8}{:>12}{:>12}{:>12}",
+                result.day,
+                stage,
+                format_duration(timings.min),
+                format_duration(timings.median),
+                format_duration(timings.mean)
+            ));
+            if let Some(memory) = result.memory {
+                table.push_str(&format!(
+                    "{:>12}{:>10}",
+                    format_bytes(memory[i].peak_bytes),
+                    memory[i].allocations
+                ));
+            }
+            table.push('\n');
+        }
+    }
+    table
+}
+
+/// Median timings per stage, with the same day links as the Readme. Peak
+/// heap usage per stage follows when it was measured.
+pub fn render_markdown(year: u32, results: &[DayBenchmark]) -> String {
+    let with_memory = results.iter().any(|r| r.memory.is_some());
+    let mut table = String::from("| Day | Parse | Part 1 | Part 2 |");
+    if with_memory {
+        table.push_str(" Parse peak | Part 1 peak | Part 2 peak |");
+    }
+    table.push_str("\n|---|---:|---:|---:|");
+    if with_memory {
+        table.push_str("---:|---:|---:|");
+    }
+    table.push('\n');
+    for result in results {
+        table.push_str(&format!(
+            "| [Day {}]({}) | {} | {} | {} |",
+            result.day,
+            days::source_url(year, result.day),
+            format_duration(result.parse.median),
+            format_duration(result.part1.median),
+            format_duration(result.part2.median)
+        ));
+        if with_memory {
+            for stats in result.memory.unwrap_or_default().iter() {
+                table.push_str(&format!(" {} |", format_bytes(stats.peak_bytes)));
+            }
+        }
+        table.push('\n');
+    }
+    table
+}
@@ -0,0 +1,40 @@
+use std::fmt::Display;
+use std::slice::Iter;
+use std::str::FromStr;
+
+/// Walks over `--flag [value]` style command line arguments
+pub struct Args<'a> {
+    iter: Iter<'a, String>,
+}
+
+impl<'a> Args<'a> {
+    pub fn new(args: &'a [String]) -> Args<'a> {
+        Args { iter: args.iter() }
+    }
+
+    pub fn next_flag(&mut self) -> Option<&'a str> {
+        self.iter.next().map(|s| s.as_str())
+    }
+
+    pub fn value(&mut self, flag: &str) -> Result<&'a str, String> {
+        self.iter
+            .next()
+            .map(|s| s.as_str())
+            .ok_or(format!("Missing value for {}", flag))
+    }
+
+    pub fn parse<T>(&mut self, flag: &str) -> Result<T, String>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let value = self.value(flag)?;
+        value
+            .parse::<T>()
+            .map_err(|e| format!("Invalid value for {}: {} ({})", flag, value, e))
+    }
+}
+
+pub fn unknown(flag: &str) -> String {
+    format!("Unknown argument: {}", flag)
+}
@@ -0,0 +1,56 @@
+use super::args::{self, Args};
+use aoc::bench::{self, BenchConfig};
+use aoc::days;
+use aoc::input::InputSource;
+use aoc::solution::Solver;
+
+#[derive(Debug, Default)]
+struct BenchArgs {
+    year: Option<u32>,
+    day: Option<u32>,
+    config: BenchConfig,
+    markdown: bool,
+}
+
+impl BenchArgs {
+    fn from_args(args: &[String]) -> Result<BenchArgs, String> {
+        let mut bench_args = BenchArgs::default();
+        let mut args = Args::new(args);
+        while let Some(flag) = args.next_flag() {
+            match flag {
+                "--year" => bench_args.year = Some(args.parse(flag)?),
+                "--day" => bench_args.day = Some(args.parse(flag)?),
+                "--iterations" => bench_args.config.iterations = args.parse(flag)?,
+                "--warmup" => bench_args.config.warmup = args.parse(flag)?,
+                "--markdown" => bench_args.markdown = true,
+                _ => return Err(args::unknown(flag)),
+            }
+        }
+        Ok(bench_args)
+    }
+}
+
+pub fn bench(args: &[String]) -> Result<(), String> {
+    let args = BenchArgs::from_args(args)?;
+    let year = args.year.unwrap_or_else(days::latest_year);
+    let solvers: Vec<&dyn Solver> = match args.day {
+        Some(day) => vec![days::find_solver(year, day)
+            .ok_or(format!("{} day {} is not implemented", year, day))?],
+        None => days::solvers(year).to_vec(),
+    };
+    let mut results = Vec::new();
+    for solver in solvers {
+        let raw_input = InputSource::default_for_day(year, solver.day())
+            .read()
+            .map_err(|e| e.to_string())?;
+        let result = bench::bench_solver(solver, &raw_input, &args.config)
+            .map_err(|e| format!("Day {}: {}", solver.day(), e))?;
+        results.push(result);
+    }
+    if args.markdown {
+        print!("{}", bench::render_markdown(year, &results));
+    } else {
+        print!("{}", bench::render_table(&results));
+    }
+    Ok(())
+}
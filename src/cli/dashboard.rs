@@ -0,0 +1,95 @@
+use super::args::{self, Args};
+use super::history;
+use super::run;
+use aoc::dashboard::{self, Dashboard};
+use aoc::days;
+use aoc::input::InputSource;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::style::Print;
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use std::io::{self, Write};
+
+// Puts the terminal back however the dashboard exits
+struct RawTerminal;
+
+impl RawTerminal {
+    fn enter() -> io::Result<RawTerminal> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(RawTerminal)
+    }
+}
+
+impl Drop for RawTerminal {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn draw(dashboard: &Dashboard, status: Option<&str>) -> io::Result<()> {
+    let (width, height) = terminal::size()?;
+    let (width, height) = (width as usize, height as usize);
+    let preview = dashboard::input_preview(dashboard.year, dashboard.selected_day(), height);
+    let mut lines = dashboard.render(&preview, width, height);
+    if let Some(status) = status {
+        lines.truncate(height.saturating_sub(1));
+        lines.push(status.to_string());
+    }
+    let mut stdout = io::stdout();
+    queue!(stdout, Clear(ClearType::All))?;
+    for (row, line) in lines.iter().enumerate() {
+        queue!(stdout, MoveTo(0, row as u16), Print(line))?;
+    }
+    stdout.flush()
+}
+
+fn event_loop(dashboard: &mut Dashboard) -> io::Result<()> {
+    draw(dashboard, None)?;
+    loop {
+        let key = match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => key,
+            Event::Resize(_, _) => {
+                draw(dashboard, None)?;
+                continue;
+            }
+            _ => continue,
+        };
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Up | KeyCode::Char('k') => dashboard.select_previous(),
+            KeyCode::Down | KeyCode::Char('j') => dashboard.select_next(),
+            KeyCode::Enter | KeyCode::Char('r') => {
+                let (year, day) = (dashboard.year, dashboard.selected_day());
+                if days::find_solver(year, day).is_some() {
+                    draw(dashboard, Some(&format!("Running day {}...", day)))?;
+                    let source = InputSource::default_for_day(year, day);
+                    dashboard.finish_run(run::run_day(year, day, &[1, 2], &source, true));
+                }
+            }
+            _ => continue,
+        }
+        draw(dashboard, None)?;
+    }
+}
+
+pub fn dashboard(args: &[String]) -> Result<(), String> {
+    let mut year: Option<u32> = None;
+    let mut args = Args::new(args);
+    while let Some(flag) = args.next_flag() {
+        match flag {
+            "--year" => year = Some(args.parse(flag)?),
+            _ => return Err(args::unknown(flag)),
+        }
+    }
+    let year = year.unwrap_or_else(days::latest_year);
+    let entries = history::open().load().map_err(|e| e.to_string())?;
+    let mut dashboard = Dashboard::new(year, &entries);
+    let terminal =
+        RawTerminal::enter().map_err(|e| format!("Unable to start the dashboard: {}", e))?;
+    let result = event_loop(&mut dashboard);
+    drop(terminal);
+    result.map_err(|e| e.to_string())
+}
@@ -0,0 +1,34 @@
+use super::args::{self, Args};
+use aoc::days;
+use aoc::fetch::{self, InputCache, LazyHttpBackend};
+
+// The session token is only needed once something has to be downloaded
+pub fn input_cache() -> InputCache<LazyHttpBackend> {
+    InputCache::new(fetch::default_cache_dir(), LazyHttpBackend::default())
+}
+
+pub fn fetch(args: &[String]) -> Result<(), String> {
+    let mut year: Option<u32> = None;
+    let mut day: Option<u32> = None;
+    let mut example = false;
+    let mut args = Args::new(args);
+    while let Some(flag) = args.next_flag() {
+        match flag {
+            "--year" => year = Some(args.parse(flag)?),
+            "--day" => day = Some(args.parse(flag)?),
+            "--example" => example = true,
+            _ => return Err(args::unknown(flag)),
+        }
+    }
+    let year = year.unwrap_or_else(days::latest_year);
+    let day = day.ok_or("--day is required")?;
+    let cache = input_cache();
+    let path = if example {
+        cache.example(year, day)
+    } else {
+        cache.input(year, day)
+    }
+    .map_err(|e| e.to_string())?;
+    println!("{}", path.display());
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use super::args::{self, Args};
+use aoc::days;
+use aoc::fetch::default_cache_dir;
+use aoc::history::{self, History, HistoryEntry};
+use aoc::runner::PartResult;
+use std::path::Path;
+
+const HISTORY_FILE: &str = "history.jsonl";
+
+pub fn open() -> History {
+    History::new(default_cache_dir().join(HISTORY_FILE))
+}
+
+/// Remembers `results` of a run on `raw_input`. A history that can not be
+/// written only warns, the answers are what matters.
+pub fn record(year: u32, results: &[PartResult], raw_input: &str) {
+    let input_hash = history::input_hash(raw_input);
+    let commit = history::git_commit(Path::new(env!("CARGO_MANIFEST_DIR")));
+    let entries: Vec<HistoryEntry> = results
+        .iter()
+        .map(|result| HistoryEntry::from_result(result, year, &input_hash, commit.clone()))
+        .collect();
+    if let Err(e) = open().append(&entries) {
+        eprintln!("Unable to record the results: {}", e);
+    }
+}
+
+pub fn history(args: &[String]) -> Result<(), String> {
+    let mut year: Option<u32> = None;
+    let mut day: Option<u32> = None;
+    let mut args = Args::new(args);
+    while let Some(flag) = args.next_flag() {
+        match flag {
+            "--year" => year = Some(args.parse(flag)?),
+            "--day" => day = Some(args.parse(flag)?),
+            _ => return Err(args::unknown(flag)),
+        }
+    }
+    let year = year.unwrap_or_else(days::latest_year);
+    let day = day.ok_or("--day is required")?;
+    let entries = open().load().map_err(|e| e.to_string())?;
+    print!("{}", history::render_history(&entries, year, day));
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use super::args::{self, Args};
+use aoc::leaderboard::{self, Leaderboard};
+use std::path::PathBuf;
+
+pub fn leaderboard(args: &[String]) -> Result<(), String> {
+    let mut file: Option<PathBuf> = None;
+    let mut markdown = false;
+    let mut args = Args::new(args);
+    while let Some(flag) = args.next_flag() {
+        match flag {
+            "--file" => file = Some(PathBuf::from(args.value(flag)?)),
+            "--markdown" => markdown = true,
+            _ => return Err(args::unknown(flag)),
+        }
+    }
+    let file = file.ok_or("--file is required")?;
+    let standings = Leaderboard::load(&file)
+        .and_then(|board| leaderboard::standings(&board))
+        .map_err(|e| e.to_string())?;
+    if markdown {
+        print!("{}", leaderboard::render_markdown(&standings));
+    } else {
+        print!("{}", leaderboard::render_table(&standings));
+    }
+    Ok(())
+}
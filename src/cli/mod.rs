@@ -0,0 +1,45 @@
+mod args;
+mod bench;
+mod dashboard;
+mod fetch;
+mod history;
+mod leaderboard;
+mod new;
+mod readme;
+mod run;
+mod sonar;
+mod submit;
+mod watch;
+
+const USAGE: &str = "Usage:
+    aoc run [--year <Y>] --day <N> [--part <1|2>] [--input <path|-> | --input-str <data> | --cached] [--format <plain|json|csv>]
+        [--isolate [--timeout <secs>] [--memory-mb <MB>]] [--no-history]
+    aoc run [--year <Y>] --all [--format <plain|json|csv> | --parallel [--threads <N>] | --isolate ...]
+    aoc run [--year <Y>] --users <dir> [--day <N>] [--part <1|2>] [--threads <N>]
+    aoc bench [--year <Y>] [--day <N>] [--iterations <N>] [--warmup <N>] [--markdown]
+    aoc new [--year <Y>] --day <N>
+    aoc fetch [--year <Y>] --day <N> [--example]
+    aoc dashboard [--year <Y>]
+    aoc history [--year <Y>] --day <N>
+    aoc readme
+    aoc leaderboard --file <leaderboard.json> [--markdown]
+    aoc sonar [--input <path|->] [--csv] [--width <N>] [--stats] [--sparkline [--columns <N>]] [--svg <path>]
+    aoc submit [--year <Y>] --day <N> --part <1|2> [--answer <answer> | --cached]
+    aoc watch [--year <Y>] --day <N> [--interval <ms>]";
+
+pub fn dispatch(args: &[String]) -> Result<(), String> {
+    match args.first().map(|s| s.as_str()) {
+        Some("run") => run::run(&args[1..]),
+        Some("bench") => bench::bench(&args[1..]),
+        Some("new") => new::new(&args[1..]),
+        Some("fetch") => fetch::fetch(&args[1..]),
+        Some("dashboard") => dashboard::dashboard(&args[1..]),
+        Some("history") => history::history(&args[1..]),
+        Some("readme") => readme::readme(&args[1..]),
+        Some("leaderboard") => leaderboard::leaderboard(&args[1..]),
+        Some("sonar") => sonar::sonar(&args[1..]),
+        Some("submit") => submit::submit(&args[1..]),
+        Some("watch") => watch::watch(&args[1..]),
+        _ => Err(USAGE.to_string()),
+    }
+}
@@ -0,0 +1,25 @@
+use super::args::{self, Args};
+use aoc::days;
+use aoc::scaffold;
+use std::path::Path;
+
+pub fn new(args: &[String]) -> Result<(), String> {
+    let mut year: Option<u32> = None;
+    let mut day: Option<u32> = None;
+    let mut args = Args::new(args);
+    while let Some(flag) = args.next_flag() {
+        match flag {
+            "--year" => year = Some(args.parse(flag)?),
+            "--day" => day = Some(args.parse(flag)?),
+            _ => return Err(args::unknown(flag)),
+        }
+    }
+    let year = year.unwrap_or_else(days::latest_year);
+    let day = day.ok_or("--day is required")?;
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let touched = scaffold::scaffold_day(root, year, day).map_err(|e| e.to_string())?;
+    for path in touched {
+        println!("{}", path.strip_prefix(root).unwrap_or(&path).display());
+    }
+    Ok(())
+}
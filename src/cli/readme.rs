@@ -0,0 +1,19 @@
+use super::args::{self, Args};
+use aoc::readme;
+use std::fs;
+use std::path::Path;
+
+pub fn readme(args: &[String]) -> Result<(), String> {
+    let mut args = Args::new(args);
+    if let Some(flag) = args.next_flag() {
+        return Err(args::unknown(flag));
+    }
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("Readme.md");
+    let content = fs::read_to_string(&path)
+        .map_err(|e| format!("Unable to read {}: {}", path.display(), e))?;
+    let updated =
+        readme::update_days(&content, &readme::registered_days()).map_err(|e| e.to_string())?;
+    fs::write(&path, updated).map_err(|e| format!("Unable to write {}: {}", path.display(), e))?;
+    println!("{}", path.display());
+    Ok(())
+}
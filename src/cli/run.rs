@@ -0,0 +1,328 @@
+use super::args::{self, Args};
+use super::fetch;
+use super::history;
+use aoc::accounts;
+use aoc::days;
+use aoc::error::AocError;
+use aoc::input::InputSource;
+use aoc::isolate::{self, ChildOutcome, Limits};
+use aoc::report::{self, OutputFormat};
+use aoc::runner::{self, Job, JobReport, PartResult};
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Debug)]
+struct RunArgs {
+    year: u32,
+    day: Option<u32>,
+    part: Option<u32>,
+    input: Option<InputSource>,
+    all: bool,
+    cached: bool,
+    format: OutputFormat,
+    parallel: bool,
+    threads: Option<usize>,
+    isolate: bool,
+    limits: Limits,
+    record: bool,
+    users: Option<PathBuf>,
+}
+
+impl RunArgs {
+    fn from_args(args: &[String]) -> Result<RunArgs, String> {
+        let mut run_args = RunArgs {
+            year: days::latest_year(),
+            day: None,
+            part: None,
+            input: None,
+            all: false,
+            cached: false,
+            format: OutputFormat::Plain,
+            parallel: false,
+            threads: None,
+            isolate: false,
+            limits: Limits::default(),
+            record: true,
+            users: None,
+        };
+        let mut args = Args::new(args);
+        while let Some(flag) = args.next_flag() {
+            match flag {
+                "--all" => run_args.all = true,
+                "--cached" => run_args.cached = true,
+                "--year" => run_args.year = args.parse(flag)?,
+                "--day" => run_args.day = Some(args.parse(flag)?),
+                "--part" => run_args.part = Some(args.parse(flag)?),
+                "--format" => run_args.format = args.parse(flag)?,
+                "--parallel" => run_args.parallel = true,
+                "--threads" => run_args.threads = Some(args.parse(flag)?),
+                "--isolate" => run_args.isolate = true,
+                "--no-history" => run_args.record = false,
+                "--users" => run_args.users = Some(PathBuf::from(args.value(flag)?)),
+                "--timeout" => run_args.limits.timeout = Duration::from_secs(args.parse(flag)?),
+                "--memory-mb" => {
+                    let megabytes: u64 = args.parse(flag)?;
+                    run_args.limits.memory_bytes = Some(megabytes * 1024 * 1024)
+                }
+                "--input" => run_args.input = Some(InputSource::from_arg(args.value(flag)?)),
+                "--input-str" => {
+                    run_args.input = Some(InputSource::Inline(args.value(flag)?.to_string()))
+                }
+                _ => return Err(args::unknown(flag)),
+            }
+        }
+        Ok(run_args)
+    }
+}
+
+pub fn run_day(
+    year: u32,
+    day: u32,
+    parts: &[u32],
+    source: &InputSource,
+    record: bool,
+) -> Result<Vec<PartResult>, String> {
+    let solver =
+        days::find_solver(year, day).ok_or(format!("{} day {} is not implemented", year, day))?;
+    let error = |e: AocError| format!("Day {} ({}): {}", day, source, e);
+    let raw_input = source.read().map_err(error)?;
+    let results =
+        runner::solve_raw(solver, &raw_input, &source.to_string(), parts).map_err(error)?;
+    if record {
+        history::record(year, &results, &raw_input);
+    }
+    Ok(results)
+}
+
+fn default_threads(threads: Option<usize>) -> usize {
+    threads
+        .or_else(|| thread::available_parallelism().map(|n| n.get()).ok())
+        .unwrap_or(1)
+}
+
+// Every day, or just `day`, against each account's inputs in `dir`
+fn run_accounts(
+    dir: &Path,
+    year: u32,
+    day: Option<u32>,
+    parts: &[u32],
+    threads: Option<usize>,
+) -> Result<(), String> {
+    let solvers = match day {
+        Some(day) => vec![days::find_solver(year, day)
+            .ok_or(format!("{} day {} is not implemented", year, day))?],
+        None => days::solvers(year).to_vec(),
+    };
+    let accounts = accounts::discover(dir).map_err(|e| e.to_string())?;
+    let threads = default_threads(threads);
+    let matrix = accounts::solve_matrix(&accounts, year, &solvers, parts, threads);
+    print!("{}", accounts::render_matrix(&matrix));
+    Ok(())
+}
+
+// Prints the reports in `format`. Failures only show in the plain summary,
+// so they go to stderr for the other formats.
+fn print_reports(reports: &[JobReport], format: OutputFormat) {
+    print!("{}", report::render_reports(reports, format));
+    if format != OutputFormat::Plain {
+        for report in reports {
+            if let Err(e) = &report.outcome {
+                eprintln!("Day {} part {} failed: {}", report.day, report.part, e);
+            }
+        }
+    }
+}
+
+// Every part of every day on its own job, summarized once all are done
+fn run_parallel(
+    year: u32,
+    parts: &[u32],
+    threads: Option<usize>,
+    format: OutputFormat,
+    record: bool,
+) -> Result<(), String> {
+    let threads = default_threads(threads);
+    let mut jobs = Vec::new();
+    for solver in days::solvers(year).iter() {
+        for part in parts {
+            jobs.push(Job {
+                solver: *solver,
+                source: InputSource::default_for_day(year, solver.day()),
+                part: *part,
+            });
+        }
+    }
+    let reports = runner::solve_parallel(&jobs, threads);
+    print_reports(&reports, format);
+    if record {
+        for solver in days::solvers(year).iter() {
+            let results: Vec<PartResult> = reports
+                .iter()
+                .filter(|r| r.day == solver.day())
+                .filter_map(|r| r.outcome.clone().ok())
+                .collect();
+            let raw_input = InputSource::default_for_day(year, solver.day()).read();
+            if let (false, Ok(raw_input)) = (results.is_empty(), raw_input) {
+                history::record(year, &results, &raw_input);
+            }
+        }
+    }
+    let failed = reports.iter().filter(|r| r.outcome.is_err()).count();
+    if failed > 0 {
+        return Err(format!("{} of {} parts failed", failed, reports.len()));
+    }
+    Ok(())
+}
+
+// Runs the day in a child process of this binary, within `limits`
+fn run_isolated_day(
+    year: u32,
+    day: u32,
+    parts: &[u32],
+    source: &InputSource,
+    limits: &Limits,
+    record: bool,
+) -> Result<Vec<JobReport>, String> {
+    let exe = env::current_exe().map_err(|e| format!("Unable to find the runner: {}", e))?;
+    let mut command = Command::new(exe);
+    command.args(["run", "--format", "json"]);
+    command.args(["--year", &year.to_string(), "--day", &day.to_string()]);
+    if let [part] = parts {
+        command.args(["--part", &part.to_string()]);
+    }
+    // The child records its own results
+    if !record {
+        command.arg("--no-history");
+    }
+    match source {
+        InputSource::File(path) => command.arg("--input").arg(path),
+        InputSource::Inline(data) => command.args(["--input-str", data]),
+        InputSource::Stdin => return Err("--isolate can not read from stdin".to_string()),
+    };
+    let outcome = isolate::run_limited(command, limits).map_err(|e| e.to_string())?;
+    let results: Result<Vec<PartResult>, String> = match &outcome {
+        ChildOutcome::Finished { stdout } => serde_json::from_str(stdout)
+            .map_err(|e| format!("Unexpected output from the child: {}", e)),
+        failed => Err(failed.to_string()),
+    };
+    Ok(match results {
+        Ok(results) => results
+            .into_iter()
+            .map(|result| JobReport {
+                day,
+                part: result.part,
+                outcome: Ok(result),
+            })
+            .collect(),
+        Err(e) => parts
+            .iter()
+            .map(|part| JobReport {
+                day,
+                part: *part,
+                outcome: Err(e.clone()),
+            })
+            .collect(),
+    })
+}
+
+fn run_isolated(
+    year: u32,
+    days: &[(u32, InputSource)],
+    parts: &[u32],
+    limits: &Limits,
+    format: OutputFormat,
+    record: bool,
+) -> Result<(), String> {
+    let mut reports = Vec::new();
+    for (day, source) in days {
+        reports.extend(run_isolated_day(year, *day, parts, source, limits, record)?);
+    }
+    print_reports(&reports, format);
+    let failed = reports.iter().filter(|r| r.outcome.is_err()).count();
+    if failed > 0 {
+        return Err(format!("{} of {} parts failed", failed, reports.len()));
+    }
+    Ok(())
+}
+
+pub fn run(args: &[String]) -> Result<(), String> {
+    let args = RunArgs::from_args(args)?;
+    let parts: Vec<u32> = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    if let Some(dir) = &args.users {
+        if args.input.is_some() || args.cached || args.isolate {
+            return Err("--users can not be combined with an input or --isolate".to_string());
+        }
+        return run_accounts(dir, args.year, args.day, &parts, args.threads);
+    }
+    if args.all {
+        if args.day.is_some() || args.input.is_some() || args.cached {
+            return Err("--all can not be combined with --day or an input".to_string());
+        }
+        if args.parallel && args.isolate {
+            return Err("--parallel can not be combined with --isolate".to_string());
+        }
+        if args.parallel {
+            return run_parallel(args.year, &parts, args.threads, args.format, args.record);
+        }
+        if args.isolate {
+            let days: Vec<(u32, InputSource)> = days::solvers(args.year)
+                .iter()
+                .map(|solver| {
+                    let source = InputSource::default_for_day(args.year, solver.day());
+                    (solver.day(), source)
+                })
+                .collect();
+            return run_isolated(
+                args.year,
+                &days,
+                &parts,
+                &args.limits,
+                args.format,
+                args.record,
+            );
+        }
+        let mut results = Vec::new();
+        for solver in days::solvers(args.year).iter() {
+            let day = solver.day();
+            let source = InputSource::default_for_day(args.year, day);
+            results.extend(run_day(args.year, day, &parts, &source, args.record)?);
+        }
+        print!("{}", report::render(&results, args.format));
+        return Ok(());
+    }
+    if args.parallel || args.threads.is_some() {
+        return Err("--parallel and --threads need --all".to_string());
+    }
+    let day = args.day.ok_or("Either --day or --all is required")?;
+    let input = match (args.input, args.cached) {
+        (Some(_), true) => return Err("--cached can not be combined with an input".to_string()),
+        (Some(input), false) => input,
+        (None, true) => {
+            let path = fetch::input_cache()
+                .input(args.year, day)
+                .map_err(|e| e.to_string())?;
+            InputSource::File(path)
+        }
+        (None, false) => InputSource::default_for_day(args.year, day),
+    };
+    if args.isolate {
+        let days = [(day, input)];
+        return run_isolated(
+            args.year,
+            &days,
+            &parts,
+            &args.limits,
+            args.format,
+            args.record,
+        );
+    }
+    let results = run_day(args.year, day, &parts, &input, args.record)?;
+    print!("{}", report::render(&results, args.format));
+    Ok(())
+}
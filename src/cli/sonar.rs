@@ -0,0 +1,92 @@
+use super::args::{self, Args};
+use aoc::error::AocError;
+use aoc::input::{default_input_path, InputSource};
+use aoc::sonar::{self, SensorAnalyzers, SensorLog, SonarFormat};
+use std::fs::{self, File};
+use std::io::{self, BufReader};
+use std::path::{Path, PathBuf};
+
+// Streams the day 1 sonar log instead of loading it, so logs of any size
+// can be analyzed
+pub fn sonar(args: &[String]) -> Result<(), String> {
+    let mut input = InputSource::File(default_input_path(2021, 1));
+    let mut format: Option<SonarFormat> = None;
+    let mut width = 3;
+    let mut stats = false;
+    let mut svg: Option<PathBuf> = None;
+    let mut sparkline = false;
+    let mut columns = 80;
+    let mut args = Args::new(args);
+    while let Some(flag) = args.next_flag() {
+        match flag {
+            "--input" => input = InputSource::from_arg(args.value(flag)?),
+            "--csv" => format = Some(SonarFormat::Csv),
+            "--width" => width = args.parse(flag)?,
+            "--stats" => stats = true,
+            "--svg" => svg = Some(PathBuf::from(args.value(flag)?)),
+            "--sparkline" => sparkline = true,
+            "--columns" => columns = args.parse(flag)?,
+            _ => return Err(args::unknown(flag)),
+        }
+    }
+    // Quantiles and charts need every reading, so these load the whole log
+    if stats || sparkline || svg.is_some() {
+        let logs = input
+            .read()
+            .and_then(|raw| sonar::parse_log(&raw, format))
+            .map_err(|e| e.to_string())?;
+        for log in logs.iter() {
+            if logs.len() > 1 || log.sensor.is_some() {
+                println!("Sensor {}", log.label());
+            }
+            if stats {
+                let stats = sonar::depth_stats(&log.readings).ok_or("No readings to report on")?;
+                print!("{}", sonar::render_stats(&stats));
+            }
+            if sparkline {
+                print!("{}", sonar::render_sparkline(&log.readings, columns));
+            }
+            if let Some(path) = &svg {
+                let path = svg_path(path, &logs, log);
+                fs::write(&path, sonar::render_svg(&log.readings, width))
+                    .map_err(|e| format!("Unable to write {}: {}", path.display(), e))?;
+                println!("{}", path.display());
+            }
+        }
+        return Ok(());
+    }
+    let mut analyzers = SensorAnalyzers::new(width, format);
+    let source = input.to_string();
+    let result = match &input {
+        InputSource::File(path) => File::open(path)
+            .map_err(|error| AocError::io(&source, error))
+            .and_then(|file| analyzers.read(BufReader::new(file), &source)),
+        _ => analyzers.read(io::stdin().lock(), &source),
+    };
+    result.map_err(|e| e.to_string())?;
+    let summaries = analyzers.summaries();
+    for (i, (sensor, summary)) in summaries.iter().enumerate() {
+        if let Some(sensor) = sensor {
+            if i > 0 {
+                println!();
+            }
+            println!("Sensor {}", sensor);
+        }
+        print!("{}", summary);
+    }
+    Ok(())
+}
+
+// Every sensor gets its own chart, named after the sensor when there are
+// several
+fn svg_path(path: &Path, logs: &[SensorLog], log: &SensorLog) -> PathBuf {
+    if logs.len() == 1 {
+        return path.to_path_buf();
+    }
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match path.extension() {
+        Some(extension) => format!("{}-{}.{}", stem, log.label(), extension.to_string_lossy()),
+        None => format!("{}-{}", stem, log.label()),
+    };
+    path.with_file_name(name)
+}
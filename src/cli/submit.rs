@@ -0,0 +1,73 @@
+use super::args::{self, Args};
+use super::fetch;
+use aoc::days;
+use aoc::fetch::{default_cache_dir, HttpBackend};
+use aoc::input::InputSource;
+use aoc::runner;
+use aoc::submit::{SubmissionLog, Submitter, Verdict};
+
+const LOG_FILE: &str = "submissions.json";
+
+pub fn submit(args: &[String]) -> Result<(), String> {
+    let mut year = days::latest_year();
+    let mut day: Option<u32> = None;
+    let mut part: Option<u32> = None;
+    let mut answer: Option<String> = None;
+    let mut cached = false;
+    let mut args = Args::new(args);
+    while let Some(flag) = args.next_flag() {
+        match flag {
+            "--year" => year = args.parse(flag)?,
+            "--day" => day = Some(args.parse(flag)?),
+            "--part" => part = Some(args.parse(flag)?),
+            "--answer" => answer = Some(args.value(flag)?.to_string()),
+            "--cached" => cached = true,
+            _ => return Err(args::unknown(flag)),
+        }
+    }
+    let day = day.ok_or("--day is required")?;
+    let part = part.ok_or("--part is required")?;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let solver = days::find_solver(year, day)
+                .ok_or(format!("{} day {} is not implemented", year, day))?;
+            let input = if cached {
+                InputSource::File(
+                    fetch::input_cache()
+                        .input(year, day)
+                        .map_err(|e| e.to_string())?,
+                )
+            } else {
+                InputSource::default_for_day(year, day)
+            };
+            let results = runner::solve(solver, &input, &[part]).map_err(|e| e.to_string())?;
+            results[0].answer.clone()
+        }
+    };
+    let backend = HttpBackend::from_env().map_err(|e| e.to_string())?;
+    let log = SubmissionLog::open(default_cache_dir().join(LOG_FILE)).map_err(|e| e.to_string())?;
+    let mut submitter = Submitter::new(backend, log);
+    let verdict = submitter
+        .submit(year, day, part, &answer)
+        .map_err(|e| e.to_string())?;
+    let message = match verdict {
+        Verdict::Correct => "That's the right answer".to_string(),
+        Verdict::Incorrect => "That's not the right answer".to_string(),
+        Verdict::TooHigh => "Too high".to_string(),
+        Verdict::TooLow => "Too low".to_string(),
+        Verdict::RateLimited => "Answered too recently, try again later".to_string(),
+        Verdict::AlreadySolved => "Already solved".to_string(),
+        Verdict::Unknown(text) => format!("Unrecognized response: {}", text),
+    };
+    println!("Day {} part {}: {} -> {}", day, part, answer, message);
+    if let Some(last) = submitter.log().submissions().last() {
+        if let Some(retry_after) = last.retry_after {
+            println!(
+                "Wait {}s before the next submission",
+                retry_after - last.submitted_at
+            );
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use super::args::{self, Args};
+use aoc::days;
+use aoc::input::{default_input_path, example_input_path};
+use aoc::runner::PartResult;
+use aoc::watch::{self, Answers, Snapshot};
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::Duration;
+
+// Solvers are compiled in, so every run goes through cargo to pick up
+// source changes
+fn run_child(year: u32, day: u32, input: &Path) -> Result<Vec<PartResult>, String> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let output = Command::new(cargo)
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["run", "--quiet", "--", "run", "--format", "json"])
+        // Watch runs are never recorded, whatever input they run on
+        .arg("--no-history")
+        .arg("--year")
+        .arg(year.to_string())
+        .arg("--day")
+        .arg(day.to_string())
+        .arg("--input")
+        .arg(input)
+        .output()
+        .map_err(|e| format!("Unable to start cargo: {}", e))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr)
+            .trim_end()
+            .to_string());
+    }
+    serde_json::from_slice(&output.stdout).map_err(|e| format!("Unexpected run output: {}", e))
+}
+
+fn run_inputs(year: u32, day: u32) -> Option<Answers> {
+    let inputs: [(&str, PathBuf); 2] = [
+        ("example", example_input_path(year, day)),
+        ("input", default_input_path(year, day)),
+    ];
+    let mut answers = Answers::new();
+    for (label, path) in inputs.iter() {
+        if !path.exists() {
+            continue;
+        }
+        match run_child(year, day, path) {
+            Ok(results) => {
+                for result in results {
+                    answers.insert((label.to_string(), result.part), result.answer);
+                }
+            }
+            Err(e) => {
+                println!("{} failed:\n{}", label, e);
+                return None;
+            }
+        }
+    }
+    Some(answers)
+}
+
+pub fn watch(args: &[String]) -> Result<(), String> {
+    let mut year = days::latest_year();
+    let mut day: Option<u32> = None;
+    let mut interval = 500;
+    let mut args = Args::new(args);
+    while let Some(flag) = args.next_flag() {
+        match flag {
+            "--year" => year = args.parse(flag)?,
+            "--day" => day = Some(args.parse(flag)?),
+            "--interval" => interval = args.parse(flag)?,
+            _ => return Err(args::unknown(flag)),
+        }
+    }
+    let day = day.ok_or("--day is required")?;
+    let mut snapshot = Snapshot::take(&watch::watched_paths(year, day));
+    let mut previous: Option<Answers> = None;
+    println!("Watching {} day {}, press Ctrl-C to stop", year, day);
+    loop {
+        if let Some(answers) = run_inputs(year, day) {
+            print!("{}", watch::render_diff(previous.as_ref(), &answers));
+            previous = Some(answers);
+        }
+        loop {
+            thread::sleep(Duration::from_millis(interval));
+            let current = Snapshot::take(&watch::watched_paths(year, day));
+            let changed = current.changed_since(&snapshot);
+            snapshot = current;
+            if !changed.is_empty() {
+                for path in changed {
+                    println!("\nChanged: {}", path.display());
+                }
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use crate::bench::format_duration;
+use crate::days;
+use crate::history::{self, HistoryEntry};
+use crate::input::InputSource;
+use crate::runner::PartResult;
+use std::time::Duration;
+
+/// Days shown, registered or not
+pub use crate::days::LAST_DAY;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Unimplemented,
+    NotRun,
+    Solved,
+    Failed(String),
+}
+
+impl Status {
+    fn label(&self) -> &str {
+        match self {
+            Status::Unimplemented => "unimplemented",
+            Status::NotRun => "not run",
+            Status::Solved => "solved",
+            Status::Failed(_) => "failed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answer {
+    pub part: u32,
+    pub answer: String,
+    pub duration_ns: u128,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayRow {
+    pub day: u32,
+    pub status: Status,
+    /// The latest answers, taken from the history until the day is run here
+    pub answers: Vec<Answer>,
+}
+
+/// State of the dashboard, kept apart from the terminal so it can be tested
+#[derive(Debug, Clone)]
+pub struct Dashboard {
+    pub year: u32,
+    pub rows: Vec<DayRow>,
+    pub selected: usize,
+}
+
+// `None` when the day has no default input to run on
+fn default_input_hash(year: u32, day: u32) -> Option<String> {
+    let raw_input = InputSource::default_for_day(year, day).read().ok()?;
+    Some(history::input_hash(&raw_input))
+}
+
+impl Dashboard {
+    /// One row per day of `year`, filled from the solver registry and the
+    /// runs in the history on the day's default input
+    pub fn new(year: u32, history: &[HistoryEntry]) -> Dashboard {
+        let rows = (1..=LAST_DAY)
+            .map(|day| {
+                if days::find_solver(year, day).is_none() {
+                    return DayRow {
+                        day,
+                        status: Status::Unimplemented,
+                        answers: Vec::new(),
+                    };
+                }
+                // Runs on other inputs, like watch runs on the example, do
+                // not answer the day
+                let input_hash = default_input_hash(year, day);
+                let answers = [1, 2]
+                    .iter()
+                    .filter_map(|part| {
+                        history
+                            .iter()
+                            .rev()
+                            .filter(|e| Some(&e.input_hash) == input_hash.as_ref())
+                            .find(|e| e.year == year && e.day == day && e.part == *part)
+                            .map(|e| Answer {
+                                part: e.part,
+                                answer: e.answer.clone(),
+                                duration_ns: e.duration_ns,
+                            })
+                    })
+                    .collect();
+                DayRow {
+                    day,
+                    status: Status::NotRun,
+                    answers,
+                }
+            })
+            .collect();
+        Dashboard {
+            year,
+            rows,
+            selected: 0,
+        }
+    }
+
+    pub fn selected_day(&self) -> u32 {
+        self.rows[self.selected].day
+    }
+
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1).min(self.rows.len() - 1);
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Stores the outcome of running the selected day
+    pub fn finish_run(&mut self, outcome: Result<Vec<PartResult>, String>) {
+        let row = &mut self.rows[self.selected];
+        match outcome {
+            Ok(results) => {
+                row.status = Status::Solved;
+                row.answers = results
+                    .into_iter()
+                    .map(|r| Answer {
+                        part: r.part,
+                        answer: r.answer,
+                        duration_ns: r.duration_ns,
+                    })
+                    .collect();
+            }
+            Err(e) => row.status = Status::Failed(e),
+        }
+    }
+
+    /// The screen as lines of at most `width` characters, `height` lines in
+    /// total. `preview` is the start of the selected day's input.
+    pub fn render(&self, preview: &str, width: usize, height: usize) -> Vec<String> {
+        let mut lines = vec![
+            format!(
+                "Advent of code {} - up/down to select, enter to run, q to quit",
+                self.year
+            ),
+            format!(
+                "  {:>3}  {:<14}{:>16}{:>16}{:>12}",
+                "Day", "Status", "Part 1", "Part 2", "Time"
+            ),
+        ];
+        // Half the screen for the list, scrolled to keep the selection visible
+        let visible = (height / 2).saturating_sub(lines.len()).max(1);
+        let first = (self.selected + 1).saturating_sub(visible);
+        for (i, row) in self.rows.iter().enumerate().skip(first).take(visible) {
+            let answer = |part: u32| {
+                row.answers
+                    .iter()
+                    .find(|a| a.part == part)
+                    .map_or("-".to_string(), |a| a.answer.clone())
+            };
+            let total: u128 = row.answers.iter().map(|a| a.duration_ns).sum();
+            let time = if row.answers.is_empty() {
+                "-".to_string()
+            } else {
+                format_duration(Duration::from_nanos(total as u64))
+            };
+            lines.push(format!(
+                "{} {:>3}  {:<14}{:>16}{:>16}{:>12}",
+                if i == self.selected { ">" } else { " " },
+                row.day,
+                row.status.label(),
+                answer(1),
+                answer(2),
+                time
+            ));
+        }
+
+        let row = &self.rows[self.selected];
+        lines.push(String::new());
+        match &row.status {
+            Status::Unimplemented => {
+                lines.push(format!("Day {} has no solver yet", row.day));
+            }
+            Status::Failed(e) => lines.push(format!("Error: {}", e)),
+            _ => {
+                for answer in row.answers.iter() {
+                    lines.push(format!(
+                        "Part {}: {} ({})",
+                        answer.part,
+                        answer.answer,
+                        format_duration(Duration::from_nanos(answer.duration_ns as u64))
+                    ));
+                }
+            }
+        }
+        if row.status != Status::Unimplemented {
+            lines.push(format!(
+                "--- {} ---",
+                InputSource::default_for_day(self.year, row.day)
+            ));
+            let room = height.saturating_sub(lines.len());
+            lines.extend(preview.lines().take(room).map(str::to_string));
+        }
+        lines.truncate(height);
+        lines
+            .into_iter()
+            .map(|line| line.chars().take(width).collect())
+            .collect()
+    }
+}
+
+/// The first `max_lines` lines of the day's input, or why it can't be read
+pub fn input_preview(year: u32, day: u32, max_lines: usize) -> String {
+    match InputSource::default_for_day(year, day).read() {
+        Ok(input) => input
+            .lines()
+            .take(max_lines)
+            .collect::<Vec<&str>>()
+            .join("\n"),
+        Err(e) => e.to_string(),
+    }
+}
@@ -0,0 +1,53 @@
+use crate::solution::Solver;
+use std::path::{Path, PathBuf};
+
+pub mod y2021;
+
+/// Puzzles run from the 1st to the 25th of December
+pub const LAST_DAY: u32 = 25;
+
+// Every year with solvers, oldest first
+pub static YEARS: &[(u32, &[&dyn Solver])] = &[(2021, y2021::SOLVERS)];
+
+/// The solvers of `year`, none for years without any
+pub fn solvers(year: u32) -> &'static [&'static dyn Solver] {
+    YEARS
+        .iter()
+        .find(|(y, _)| *y == year)
+        .map_or(&[], |(_, solvers)| *solvers)
+}
+
+pub fn find_solver(year: u32, day: u32) -> Option<&'static dyn Solver> {
+    solvers(year).iter().find(|s| s.day() == day).copied()
+}
+
+/// The year of everything recorded before there were several years, used
+/// as the default when reading old history and submission logs
+pub fn first_year() -> u32 {
+    2021
+}
+
+/// The year commands default to when none is given
+pub fn latest_year() -> u32 {
+    YEARS
+        .iter()
+        .map(|(year, _)| *year)
+        .max()
+        .unwrap_or_else(first_year)
+}
+
+// Where the Readme links each day to
+pub fn source_url(year: u32, day: u32) -> String {
+    format!(
+        "https://github.com/Hyde46/advent_of_code_2021/blob/main/src/days/y{}/day{}.rs",
+        year, day
+    )
+}
+
+pub fn source_path(year: u32, day: u32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("days")
+        .join(format!("y{}", year))
+        .join(format!("day{}.rs", day))
+}
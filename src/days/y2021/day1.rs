@@ -0,0 +1,42 @@
+// Sonar sweep
+use crate::error::Result;
+use crate::solution::Solution;
+use crate::sonar::{count_window_increases, parse_log, SensorLog};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    type Input = Vec<SensorLog>;
+    type Answer = String;
+    const EXTRA_SOURCES: &'static [&'static str] = &["sonar.rs"];
+
+    fn parse(input: &str) -> Result<Vec<SensorLog>> {
+        parse_log(input, None)
+    }
+
+    fn part1(input: &Vec<SensorLog>) -> Result<String> {
+        Ok(per_sensor(input, |readings| {
+            count_window_increases(readings, 1)
+        }))
+    }
+
+    fn part2(input: &Vec<SensorLog>) -> Result<String> {
+        Ok(per_sensor(input, |readings| {
+            count_window_increases(readings, 3)
+        }))
+    }
+}
+
+// A bare count for a plain list of depths, one count per sensor otherwise
+fn per_sensor(logs: &[SensorLog], count: impl Fn(&[u32]) -> usize) -> String {
+    match logs {
+        [] => "0".to_string(),
+        [log] if log.sensor.is_none() => count(&log.readings).to_string(),
+        _ => logs
+            .iter()
+            .map(|log| format!("{}: {}", log.label(), count(&log.readings)))
+            .collect::<Vec<String>>()
+            .join(", "),
+    }
+}
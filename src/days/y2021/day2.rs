@@ -0,0 +1,161 @@
+use crate::error::{parse_field, AocError, Result};
+use crate::solution::Solution;
+use crate::tokenizer::{non_blank_lines, words};
+use std::fmt::Display;
+use std::ops::{Add, Neg};
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct Pos<T> {
+    x: T,
+    y: T,
+    aim: T,
+}
+
+/// Arithmetic that reports overflow as `None`, so huge instructions fail the
+/// dive instead of panicking
+pub trait CheckedOps: Sized {
+    fn add_checked(self, other: Self) -> Option<Self>;
+    fn mul_checked(self, other: Self) -> Option<Self>;
+}
+
+impl CheckedOps for i32 {
+    fn add_checked(self, other: i32) -> Option<i32> {
+        self.checked_add(other)
+    }
+
+    fn mul_checked(self, other: i32) -> Option<i32> {
+        self.checked_mul(other)
+    }
+}
+
+impl<'b, T> Add<&'b Pos<T>> for &Pos<T>
+where
+    T: CheckedOps,
+    T: Copy,
+{
+    type Output = Option<Pos<T>>;
+    fn add(self, other: &'b Pos<T>) -> Option<Pos<T>> {
+        Some(Pos {
+            x: self.x.add_checked(other.x)?,
+            y: self.y.add_checked(other.y)?,
+            aim: self.aim,
+        })
+    }
+}
+
+impl<T> Pos<T> {
+    fn new(x: T, y: T, aim: T) -> Pos<T> {
+        Self { x, y, aim }
+    }
+
+    fn position_hash(self) -> Option<T>
+    where
+        T: CheckedOps,
+    {
+        self.x.mul_checked(self.y)
+    }
+
+    fn from_str(line_number: usize, string: &str) -> Result<Pos<T>>
+    where
+        T: FromStr,
+        T: Default,
+        T: Neg<Output = T>,
+        <T as FromStr>::Err: Display,
+    {
+        let mut value = words(string);
+        let lhs = value.next().unwrap_or_default();
+        let rhs = value.next().ok_or_else(|| {
+            AocError::parse(
+                line_number,
+                string.chars().count() + 1,
+                string,
+                "Missing instruction value",
+            )
+        })?;
+        let rhs_value = parse_field::<T>(line_number, string, rhs)?;
+        let pos = match lhs {
+            "forward" => Pos {
+                x: rhs_value,
+                y: T::default(),
+                aim: T::default(),
+            },
+            "up" => Pos {
+                x: T::default(),
+                y: -rhs_value,
+                aim: T::default(),
+            },
+            "down" => Pos {
+                x: T::default(),
+                y: rhs_value,
+                aim: T::default(),
+            },
+            _ => {
+                return Err(AocError::parse_field(
+                    line_number,
+                    string,
+                    lhs,
+                    "Invalid instruction found",
+                ))
+            }
+        };
+        Ok(pos)
+    }
+
+    fn aim(self, other: &Pos<T>) -> Option<Pos<T>>
+    where
+        T: CheckedOps,
+        T: Copy,
+        T: PartialOrd,
+        T: Default,
+    {
+        // Could generalize the aiming method to be part of add() if generic T would implement a Trait for identity value
+        Some(Pos {
+            x: self.x.add_checked(other.x)?,
+            y: self.y.add_checked(self.aim.mul_checked(other.x)?)?,
+            aim: self.aim.add_checked(other.y)?,
+        })
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+    type Input = Vec<Pos<i32>>;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Vec<Pos<i32>>> {
+        non_blank_lines(input)
+            .map(|line| Pos::from_str(line.number, line.text))
+            .collect()
+    }
+
+    fn part1(input: &Vec<Pos<i32>>) -> Result<i32> {
+        solve_dive(input)
+    }
+
+    fn part2(input: &Vec<Pos<i32>>) -> Result<i32> {
+        solve_aimed_dive(input)
+    }
+}
+
+fn overflow() -> AocError {
+    AocError::solver("The submarine dives past what an i32 can hold")
+}
+
+pub fn solve_dive(inputs: &[Pos<i32>]) -> Result<i32> {
+    inputs
+        .iter()
+        .try_fold(Pos::new(0, 0, 0), |acc, i| acc.add(i))
+        .and_then(Pos::position_hash)
+        .ok_or_else(overflow)
+}
+
+pub fn solve_aimed_dive(inputs: &[Pos<i32>]) -> Result<i32> {
+    inputs
+        .iter()
+        .try_fold(Pos::new(0, 0, 0), |acc, i| acc.aim(i))
+        .and_then(Pos::position_hash)
+        .ok_or_else(overflow)
+}
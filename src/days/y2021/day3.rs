@@ -0,0 +1,206 @@
+use crate::error::{AocError, Result};
+use crate::solution::Solution;
+use crate::tokenizer::non_blank_lines;
+
+// Ratings are read as integers, and their products must fit an i64
+const MAX_BITS: usize = 31;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    type Input = Vec<Diagnostic>;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Vec<Diagnostic>> {
+        let mut lines = non_blank_lines(input).peekable();
+        // Every diagnostic needs as many bits as the first one
+        let first = match lines.peek() {
+            Some(line) => *line,
+            None => return Err(AocError::validation("No diagnostics found")),
+        };
+        let bits = first.text.trim().chars().count();
+        if bits > MAX_BITS {
+            return Err(AocError::parse(
+                first.number,
+                MAX_BITS + 1,
+                first.text.trim(),
+                format!("Diagnostics can have at most {} bits", MAX_BITS),
+            ));
+        }
+        lines
+            .map(|line| {
+                Diagnostic::values_from_str(line.number, line.text.trim(), bits)
+                    .map(Diagnostic::new)
+            })
+            .collect()
+    }
+
+    fn part1(input: &Vec<Diagnostic>) -> Result<i64> {
+        Ok(calculate_power_consumption(input))
+    }
+
+    fn part2(input: &Vec<Diagnostic>) -> Result<i64> {
+        calculate_life_support(input)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    accum: Vec<i32>,
+}
+
+impl Diagnostic {
+    fn new(values: Vec<i32>) -> Diagnostic {
+        Diagnostic { accum: values }
+    }
+
+    fn zeroed(bits: usize) -> Diagnostic {
+        Diagnostic {
+            accum: vec![0; bits],
+        }
+    }
+
+    fn add(self, values: &[i32]) -> Diagnostic {
+        let mut accum = Vec::new();
+        values.iter().enumerate().for_each(|(i, x)| {
+            let mut val = 1;
+            if *x == 0 {
+                val = -1;
+            }
+            accum.push(self.accum[i] + val);
+        });
+        Diagnostic { accum }
+    }
+
+    fn values_from_str(line_number: usize, string: &str, bits: usize) -> Result<Vec<i32>> {
+        let length = string.chars().count();
+        if length != bits {
+            return Err(AocError::parse(
+                line_number,
+                length.min(bits) + 1,
+                string,
+                format!("Wrong diagnostic string length, expected {} bits", bits),
+            ));
+        }
+        let mut diagnostic_values = Vec::new();
+        for (i, c) in string.chars().enumerate() {
+            let parsed_val = match c {
+                '0' => 0,
+                '1' => 1,
+                _ => {
+                    return Err(AocError::parse(
+                        line_number,
+                        i + 1,
+                        &c.to_string(),
+                        "Diagnostic bits must be 0 or 1",
+                    ))
+                }
+            };
+            diagnostic_values.push(parsed_val);
+        }
+        Ok(diagnostic_values)
+    }
+
+    fn gamma_rate(&self) -> i64 {
+        let gamma = self.accum.iter().map(|a| (*a > 0) as i32).collect();
+        Diagnostic::vec_to_integer(gamma)
+    }
+
+    fn epsilon_rate(&self) -> i64 {
+        let epsilon = self.accum.iter().map(|a| (*a < 0) as i32).collect();
+        Diagnostic::vec_to_integer(epsilon)
+    }
+
+    // Bits are most significant first, at most `MAX_BITS` of them
+    fn vec_to_integer(vec: Vec<i32>) -> i64 {
+        vec.iter()
+            .fold(0, |number, bit| (number << 1) | i64::from(*bit))
+    }
+}
+
+fn most_common_value_at_pos(vec: &[Diagnostic], pos: i32, default_at_0: i32) -> i32 {
+    let result = vec.iter().fold(0, |accum, x| {
+        let value = x.accum[pos as usize];
+        match value {
+            0 => accum - 1,
+            1 => accum + 1,
+            _ => accum,
+        }
+    });
+    if result == 0 {
+        return default_at_0;
+    }
+    (result > 0) as i32
+}
+
+fn least_common_value_at_pos(vec: &[Diagnostic], pos: i32, default_at_0: i32) -> i32 {
+    let result = vec.iter().fold(0, |accum, x| {
+        let value = x.accum[pos as usize];
+        match value {
+            0 => accum - 1,
+            1 => accum + 1,
+            _ => accum,
+        }
+    });
+    if result == 0 {
+        return default_at_0;
+    }
+    (result < 0) as i32
+}
+
+pub fn calculate_power_consumption(diagnostics: &[Diagnostic]) -> i64 {
+    let bits = diagnostics.first().map_or(0, |d| d.accum.len());
+    let accum_diagnostic: Diagnostic = diagnostics
+        .iter()
+        .fold(Diagnostic::zeroed(bits), |acc, diag| acc.add(&diag.accum));
+
+    let gamma = accum_diagnostic.gamma_rate();
+    let epsilon = accum_diagnostic.epsilon_rate();
+
+    gamma * epsilon
+}
+
+fn calculate_oxygen_rating(diagnostics: &[Diagnostic]) -> Result<i64> {
+    let mut d = diagnostics.to_vec();
+    let bits = d.first().map_or(0, |d| d.accum.len()) as i32;
+    let mut bit_flag_position = 0;
+    while d.len() > 1 && bit_flag_position < bits {
+        let most_common_bit = most_common_value_at_pos(&d, bit_flag_position, 1);
+        d.retain(|x| x.accum[bit_flag_position as usize] == most_common_bit);
+        bit_flag_position += 1;
+    }
+    if d.len() != 1 {
+        return Err(AocError::solver(format!(
+            "{} diagnostics left after filtering for the oxygen rating",
+            d.len()
+        )));
+    }
+    let mvp_diagnostics = d[0].accum.clone();
+    Ok(Diagnostic::vec_to_integer(mvp_diagnostics))
+}
+
+fn calculate_co2_rating(diagnostics: &[Diagnostic]) -> Result<i64> {
+    let mut d = diagnostics.to_vec();
+    let bits = d.first().map_or(0, |d| d.accum.len()) as i32;
+    let mut bit_flag_position = 0;
+    while d.len() > 1 && bit_flag_position < bits {
+        let most_common_bit = least_common_value_at_pos(&d, bit_flag_position, 0);
+        d.retain(|x| x.accum[bit_flag_position as usize] == most_common_bit);
+        bit_flag_position += 1;
+    }
+    if d.len() != 1 {
+        return Err(AocError::solver(format!(
+            "{} diagnostics left after filtering for the co2 rating",
+            d.len()
+        )));
+    }
+    let mvp_diagnostics = d[0].accum.clone();
+    Ok(Diagnostic::vec_to_integer(mvp_diagnostics))
+}
+
+pub fn calculate_life_support(diagnostics: &[Diagnostic]) -> Result<i64> {
+    let oxygen_rating = calculate_oxygen_rating(diagnostics)?;
+    let co2_rating = calculate_co2_rating(diagnostics)?;
+    Ok(oxygen_rating * co2_rating)
+}
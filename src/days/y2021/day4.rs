@@ -0,0 +1,210 @@
+use crate::error::{parse_field, AocError, Result};
+use crate::solution::Solution;
+use crate::tokenizer::{fields, records, words, InputLine};
+use std::iter::Iterator;
+
+#[derive(Debug, Clone)]
+pub struct BingoBoard {
+    // Would be better to save which numbers are still
+    // missing for the board to be a winning board. Would make this much much faster and efficient
+    values: Vec<i32>,
+
+    marked_values: Vec<i32>,
+}
+
+impl BingoBoard {
+    fn new(values: Vec<i32>) -> BingoBoard {
+        BingoBoard {
+            values,
+            marked_values: Vec::new(),
+        }
+    }
+
+    fn from_iter(values_iter: &mut dyn Iterator<Item = &InputLine>) -> Result<BingoBoard> {
+        let mut board = BingoBoard::new(Vec::new());
+        for line in values_iter {
+            let mut bingo_values: Vec<i32> = words(line.text)
+                .map(|bingo_str_value| parse_field::<i32>(line.number, line.text, bingo_str_value))
+                .collect::<Result<Vec<i32>>>()?;
+            if bingo_values.len() != 5 {
+                return Err(AocError::parse(
+                    line.number,
+                    1,
+                    line.text,
+                    format!("Expected 5 bingo values, found {}", bingo_values.len()),
+                ));
+            }
+            board.values.append(&mut bingo_values);
+        }
+        Ok(board)
+    }
+
+    fn get_row_values(&self, row: i32) -> Vec<i32> {
+        let mut values = Vec::new();
+        for i in 0..5 {
+            let offset = i + (row * 5);
+            values.push(self.values[offset as usize]);
+        }
+        values
+    }
+
+    fn get_column_values(&self, col: i32) -> Vec<i32> {
+        let mut values: Vec<i32> = Vec::new();
+        for i in 0..5 {
+            let index = (col + (i * 5)) as usize;
+            values.push(self.values[index]);
+        }
+        values
+    }
+
+    fn did_win_vertical(&mut self, values: &[i32]) -> bool {
+        let mut number_counter = 0;
+        for i in 0..5 {
+            let column = self.get_column_values(i);
+            for col_value in column {
+                if values.contains(&col_value) {
+                    number_counter += 1;
+                }
+            }
+            if number_counter == 5 {
+                return true;
+            }
+            number_counter = 0;
+        }
+        false
+    }
+
+    fn did_win_horizontal(&mut self, values: &[i32]) -> bool {
+        let mut number_counter = 0;
+        for i in 0..5 {
+            let row = self.get_row_values(i);
+            for row_value in row {
+                if values.contains(&row_value) {
+                    number_counter += 1;
+                }
+            }
+            if number_counter == 5 {
+                return true;
+            }
+            number_counter = 0;
+        }
+        false
+    }
+
+    fn did_win(&mut self, values: &[i32]) -> bool {
+        self.did_win_vertical(values) || self.did_win_horizontal(values)
+    }
+
+    fn winning_value(&self, values: &[i32]) -> i32 {
+        let left_numbers_sum: i32 = self
+            .values
+            .iter()
+            .filter(|v| !self.marked_values.contains(v))
+            .sum();
+        let last_called_number = values.last().unwrap();
+        left_numbers_sum * last_called_number
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    type Input = (Vec<i32>, Vec<BingoBoard>);
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<(Vec<i32>, Vec<BingoBoard>)> {
+        read_input(input)
+    }
+
+    fn part1(input: &(Vec<i32>, Vec<BingoBoard>)) -> Result<i32> {
+        let (numbers, bingo_boards) = input;
+        find_bingo_winner(numbers, bingo_boards.clone())
+    }
+
+    fn part2(input: &(Vec<i32>, Vec<BingoBoard>)) -> Result<i32> {
+        let (numbers, bingo_boards) = input;
+        find_last_bingo_winner(numbers, bingo_boards.clone())
+    }
+}
+
+fn read_input(str_data: &str) -> Result<(Vec<i32>, Vec<BingoBoard>)> {
+    // Numbers and boards are separated by blank lines
+    let mut blocks = records(str_data).into_iter();
+    let numbers_block = blocks
+        .next()
+        .ok_or_else(|| AocError::validation("No bingo numbers found"))?;
+    if numbers_block.len() != 1 {
+        return Err(AocError::validation(format!(
+            "Expected a single line of bingo numbers at line {}",
+            numbers_block[0].number
+        )));
+    }
+    // Prepare winning numbers
+    let numbers_line = numbers_block[0];
+    let numbers: Vec<i32> = fields(numbers_line.text, ',')
+        .map(|s| parse_field::<i32>(numbers_line.number, numbers_line.text, s))
+        .collect::<Result<Vec<i32>>>()?;
+    // Parse Bingo boards
+    let mut boards: Vec<BingoBoard> = Vec::new();
+    for block in blocks {
+        // Get 5x5 bingo board values
+        if block.len() != 5 {
+            return Err(AocError::validation(format!(
+                "Bingo board starting at line {} has {} rows instead of 5",
+                block[0].number,
+                block.len()
+            )));
+        }
+        // Create bingo board from values
+        boards.push(BingoBoard::from_iter(&mut block.iter())?);
+    }
+    Ok((numbers, boards))
+}
+
+pub fn find_bingo_winner(numbers: &[i32], mut bingo_boards: Vec<BingoBoard>) -> Result<i32> {
+    let mut current_drawn_numbers: Vec<i32> = Vec::new();
+    for val in numbers.iter() {
+        current_drawn_numbers.push(*val);
+
+        for board in &mut bingo_boards {
+            board.marked_values.push(*val);
+            if board.did_win(&current_drawn_numbers) {
+                return Ok(board.winning_value(&current_drawn_numbers));
+            }
+        }
+    }
+    Err(AocError::solver("No bingo board won"))
+}
+
+pub fn find_last_bingo_winner(numbers: &[i32], mut bingo_boards: Vec<BingoBoard>) -> Result<i32> {
+    let mut current_drawn_numbers: Vec<i32> = Vec::new();
+    let board_len = bingo_boards.len();
+    let mut boards_won: Vec<i32> = Vec::new();
+
+    for val in numbers.iter() {
+        current_drawn_numbers.push(*val);
+
+        let mut index_counter = 0;
+        for board in &mut bingo_boards {
+            board.marked_values.push(*val);
+            if boards_won.contains(&index_counter) {
+                index_counter += 1;
+                continue;
+            }
+            if board.did_win(&current_drawn_numbers) {
+                if !boards_won.contains(&index_counter) {
+                    boards_won.push(index_counter);
+                }
+                if boards_won.len() == board_len {
+                    // Find last board and get value
+                    let last_board_index = *boards_won.last().unwrap();
+                    return Ok(bingo_boards[last_board_index as usize]
+                        .winning_value(&current_drawn_numbers));
+                }
+            }
+            index_counter += 1;
+        }
+    }
+    Err(AocError::solver("Not every bingo board won"))
+}
@@ -1,11 +1,11 @@
+use crate::error::{parse_field, AocError, Result};
+use crate::solution::Solution;
+use crate::tokenizer::non_blank_lines;
 use itertools::zip;
 use std::collections::HashMap;
-use std::fs;
 
 use regex::Regex;
 
-const INPUT: &str = "src/inputs/day-5-example.txt";
-
 #[derive(Debug, Clone)]
 struct Point {
     x: i32,
@@ -13,33 +13,28 @@ struct Point {
 }
 
 #[derive(Debug)]
-struct Line {
+pub struct Line {
     start: Point,
     end: Point,
 }
 
 impl Line {
-    fn from_str(s: &str) -> Line {
+    fn from_str(line_number: usize, s: &str) -> Result<Line> {
         let re = Regex::new(r"^(\d{1,3}),(\d{1,3}) -> (\d{1,3}),(\d{1,3})").unwrap();
-        let cap = re.captures(s).unwrap();
-        Line {
+        let cap = re.captures(s).ok_or_else(|| {
+            AocError::parse(line_number, 1, s, "Expected a line like `x1,y1 -> x2,y2`")
+        })?;
+        let coordinate = |i: usize| parse_field::<i32>(line_number, s, &cap[i]);
+        Ok(Line {
             start: Point {
-                x: cap
-                    .get(1)
-                    .map_or(-1, |m| m.as_str().parse::<i32>().unwrap()),
-                y: cap
-                    .get(2)
-                    .map_or(-1, |m| m.as_str().parse::<i32>().unwrap()),
+                x: coordinate(1)?,
+                y: coordinate(2)?,
             },
             end: Point {
-                x: cap
-                    .get(3)
-                    .map_or(-1, |m| m.as_str().parse::<i32>().unwrap()),
-                y: cap
-                    .get(4)
-                    .map_or(-1, |m| m.as_str().parse::<i32>().unwrap()),
+                x: coordinate(3)?,
+                y: coordinate(4)?,
             },
-        }
+        })
     }
 
     fn is_horizontal(&self) -> bool {
@@ -58,14 +53,14 @@ impl Line {
     fn get_coordinates_vec(&self) -> Vec<Point> {
         if self.is_vertical() {
             let (start, end) = super_complex_sort(self.start.y, self.end.y);
-            return ((start)..(end + 1))
+            ((start)..(end + 1))
                 .map(|y| Point { x: self.start.x, y })
-                .collect();
+                .collect()
         } else if self.is_horizontal() {
             let (start, end) = super_complex_sort(self.start.x, self.end.x);
-            return ((start)..(end + 1))
+            ((start)..(end + 1))
                 .map(|x| Point { x, y: self.start.y })
-                .collect();
+                .collect()
         } else {
             let range_x = produce_diagonal_range(self.start.x, self.end.x);
             let range_y = produce_diagonal_range(self.start.y, self.end.y);
@@ -95,21 +90,30 @@ fn super_complex_sort(first: i32, second: i32) -> (i32, i32) {
     }
 }
 
-fn read_input(input: &str) -> Vec<Line> {
-    let str_data = fs::read_to_string(input).expect("Unable to open input");
-    let vectorized: Vec<Line> = str_data
-        .split("\n")
-        .map(|string| Line::from_str(string))
-        .collect();
-    vectorized
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    type Input = Vec<Line>;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Vec<Line>> {
+        non_blank_lines(input)
+            .map(|line| Line::from_str(line.number, line.text.trim()))
+            .collect()
+    }
+
+    fn part1(input: &Vec<Line>) -> Result<i32> {
+        Ok(solve_simple_overlaps(input))
+    }
+
+    fn part2(input: &Vec<Line>) -> Result<i32> {
+        Ok(solve_complex_overlaps(input))
+    }
 }
 
-fn solve_simple_overlaps(input: &str) -> i32 {
-    let lines = read_input(input);
-    let non_diagonal_lines: Vec<Line> = lines
-        .into_iter()
-        .filter(|line| !line.is_diagonal())
-        .collect();
+pub fn solve_simple_overlaps(lines: &[Line]) -> i32 {
+    let non_diagonal_lines: Vec<&Line> = lines.iter().filter(|line| !line.is_diagonal()).collect();
 
     let mut points = Vec::<Point>::new();
     non_diagonal_lines
@@ -127,9 +131,7 @@ fn solve_simple_overlaps(input: &str) -> i32 {
         .fold(0, |acc, (_k, v)| if v >= 2 { acc + 1 } else { acc })
 }
 
-fn solve_complex_overlaps(input: &str) -> i32 {
-    let lines = read_input(input);
-
+pub fn solve_complex_overlaps(lines: &[Line]) -> i32 {
     let mut points = Vec::<Point>::new();
     lines
         .iter()
@@ -145,13 +147,3 @@ fn solve_complex_overlaps(input: &str) -> i32 {
     m.into_iter()
         .fold(0, |acc, (_k, v)| if v >= 2 { acc + 1 } else { acc })
 }
-fn main() {
-    println!(
-        "Hydrothermal simple overlaps: {}",
-        solve_simple_overlaps(INPUT)
-    );
-    println!(
-        "Hydrothermal complex overlaps: {}",
-        solve_complex_overlaps(INPUT)
-    );
-}
@@ -0,0 +1,58 @@
+use crate::error::{parse_field, AocError, Result};
+use crate::solution::Solution;
+use crate::tokenizer::{fields, single_line};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    type Input = Vec<u64>;
+    type Answer = usize;
+
+    fn parse(input: &str) -> Result<Vec<u64>> {
+        let line = single_line(input)?;
+        let fish = fields(line.text, ',')
+            .map(|string| parse_field::<u64>(line.number, line.text, string))
+            .collect::<Result<Vec<u64>>>()?;
+        // 9 possible fish states
+        if let Some(f) = fish.iter().find(|f| **f > 8) {
+            return Err(AocError::validation(format!(
+                "Fish timer {} is out of range 0..=8",
+                f
+            )));
+        }
+        Ok(fish)
+    }
+
+    fn part1(input: &Vec<u64>) -> Result<usize> {
+        Ok(simulate_cached_fish(input, 80))
+    }
+
+    fn part2(input: &Vec<u64>) -> Result<usize> {
+        Ok(simulate_cached_fish(input, 256))
+    }
+}
+
+pub fn simulate_cached_fish(fish: &[u64], days: usize) -> usize {
+    // 9 possible fish states
+    let mut fish_cache: Vec<usize> = vec![0; 9];
+    let mut fish_swap: Vec<usize> = vec![0; 9];
+    fish.iter().for_each(|f| fish_cache[*f as usize] += 1);
+    for _i in 1..days + 1 {
+        fish_cache.iter().enumerate().for_each(|(i, f)| {
+            let target_swap_index = if i == 0 { 6 } else { i - 1 };
+            fish_swap[target_swap_index] = if fish_swap[target_swap_index] == 0 {
+                *f
+            } else {
+                *f + fish_swap[target_swap_index]
+            };
+            if i == 0 {
+                fish_swap[8] = *f;
+            }
+        });
+        fish_cache = fish_swap.clone();
+        // clear swap
+        fish_swap = vec![0; 9];
+    }
+    fish_cache.iter().fold(0, |acc, f| acc + *f)
+}
@@ -0,0 +1,89 @@
+use crate::error::{parse_field, AocError, Result};
+use crate::solution::Solution;
+use crate::tokenizer::{fields, single_line};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+    type Input = Vec<i32>;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Vec<i32>> {
+        let line = single_line(input)?;
+        fields(line.text, ',')
+            .map(|string| {
+                let position = parse_field::<i32>(line.number, line.text, string)?;
+                if position < 0 {
+                    return Err(AocError::parse_field(
+                        line.number,
+                        line.text,
+                        string,
+                        "Crab positions can not be negative",
+                    ));
+                }
+                Ok(position)
+            })
+            .collect()
+    }
+
+    // Median would be enough for p1, but not for p2 anymore
+    fn part1(input: &Vec<i32>) -> Result<i64> {
+        align_crabs(input, false)
+    }
+
+    fn part2(input: &Vec<i32>) -> Result<i64> {
+        align_crabs(input, true)
+    }
+}
+
+// Fuel to move `distance` steps, where every step costs one more than the
+// one before when `distanced`
+fn fuel_for(distance: i128, distanced: bool) -> i128 {
+    match distanced {
+        true => distance * (distance + 1) / 2,
+        false => distance,
+    }
+}
+
+// Positions and counts both fit an i32, so the total always fits an i128
+fn calculate_fuel_count(x: i128, crab_cache: &HashMap<i32, i32>, distanced: bool) -> i128 {
+    crab_cache.iter().fold(0, |accum, (k, v)| {
+        accum + i128::from(*v) * fuel_for((i128::from(*k) - x).abs(), distanced)
+    })
+}
+
+// The fuel needed is convex in the target position, so the cheapest one is
+// found by bisecting for where it stops going down
+pub fn align_crabs(input: &[i32], distanced: bool) -> Result<i64> {
+    let mut crabs: Vec<i32> = input.to_vec();
+    crabs.sort();
+    let (mut low, mut high) = match (crabs.first(), crabs.last()) {
+        (Some(min), Some(max)) => (i128::from(*min), i128::from(*max)),
+        _ => return Err(AocError::solver("No crabs to align")),
+    };
+    let mut crabs_cache: HashMap<i32, i32> = HashMap::new();
+    crabs.iter().for_each(|c| {
+        crabs_cache.insert(
+            *c,
+            if crabs_cache.contains_key(c) {
+                crabs_cache[c] + 1
+            } else {
+                1
+            },
+        );
+    });
+    let fuel = |x| calculate_fuel_count(x, &crabs_cache, distanced);
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if fuel(middle) <= fuel(middle + 1) {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+    i64::try_from(fuel(low))
+        .map_err(|_| AocError::solver("Aligning the crabs takes more fuel than fits an i64"))
+}
@@ -0,0 +1,217 @@
+use crate::error::{AocError, Result};
+use crate::solution::Solution;
+use crate::tokenizer::{non_blank_lines, words};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+// All sorted patterns of each entry, followed by just the sorted output values
+type Notes = (Vec<Vec<String>>, Vec<Vec<String>>);
+
+// Any 9 digit number fits an i32
+const MAX_OUTPUT_DIGITS: usize = 9;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+    type Input = Notes;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Notes> {
+        read_input(input)
+    }
+
+    fn part1(input: &Notes) -> Result<i32> {
+        let (_, outputs) = input;
+        Ok(count_easy_digits(outputs))
+    }
+
+    fn part2(input: &Notes) -> Result<i32> {
+        let (all, outputs) = input;
+        compute_output_sum(all, outputs)
+    }
+}
+
+pub fn count_easy_digits(output_values: &[Vec<String>]) -> i32 {
+    output_values.iter().fold(0, |acc, numbers| {
+        numbers.iter().fold(0, |num_acc, n| match n.len() {
+            2 | 3 | 4 | 7 => num_acc + 1,
+            _ => num_acc,
+        }) + acc
+    })
+}
+
+fn read_input(str_data: &str) -> Result<Notes> {
+    let mut outputs: Vec<Vec<String>> = Vec::new();
+    let mut vectorized: Vec<Vec<String>> = Vec::new();
+    for line in non_blank_lines(str_data) {
+        let mut split = line.text.split('|');
+        let lhs = split.next().unwrap_or_default();
+        let rhs = split.next().ok_or_else(|| {
+            AocError::parse(
+                line.number,
+                1,
+                line.text,
+                "Expected `|` between patterns and output",
+            )
+        })?;
+        let mut lhs_vec: Vec<String> = words(lhs)
+            .map(|s| s.chars().sorted().collect::<String>())
+            .collect();
+        // 1,4,7,8 are the starting point for reasoning about every other digit
+        for length in [2, 3, 4, 7].iter() {
+            if lhs_vec.iter().filter(|p| p.len() == *length).count() != 1 {
+                return Err(AocError::parse(
+                    line.number,
+                    1,
+                    lhs,
+                    format!("Expected exactly one pattern with {} segments", length),
+                ));
+            }
+        }
+        let mut rhs_vec: Vec<String> = words(rhs)
+            .map(|s| s.chars().sorted().collect::<String>())
+            .collect();
+        // The output is read as one number, which has to fit an i32
+        if rhs_vec.is_empty() || rhs_vec.len() > MAX_OUTPUT_DIGITS {
+            return Err(AocError::parse_field(
+                line.number,
+                line.text,
+                rhs,
+                format!("Expected 1 to {} output digits", MAX_OUTPUT_DIGITS),
+            ));
+        }
+        outputs.push(rhs_vec.clone());
+        lhs_vec.append(&mut rhs_vec);
+        vectorized.push(lhs_vec);
+    }
+    Ok((vectorized, outputs))
+}
+
+fn digit_contains(digit: &str, other: &str) -> bool {
+    for s in other.chars() {
+        if !digit.contains(s) {
+            return false;
+        }
+    }
+    true
+}
+
+fn subtract_string(digit: String, other: String) -> String {
+    digit.chars().filter(|c| !other.contains(*c)).collect()
+}
+
+fn reason_dictionary(input: &[String]) -> Result<HashMap<String, i32>> {
+    let mut digit_value_dict: HashMap<String, i32> = HashMap::new();
+    let mut value_digit_dict: HashMap<i32, String> = HashMap::new();
+    // 1,4,7,8 can be solved immediately
+    input.iter().for_each(|s| {
+        match s.len() {
+            2 => {
+                digit_value_dict.insert(s.clone(), 1);
+                value_digit_dict.insert(1, s.clone());
+            }
+            3 => {
+                digit_value_dict.insert(s.clone(), 7);
+                value_digit_dict.insert(7, s.clone());
+            }
+            4 => {
+                digit_value_dict.insert(s.clone(), 4);
+                value_digit_dict.insert(4, s.clone());
+            }
+            7 => {
+                digit_value_dict.insert(s.clone(), 8);
+                value_digit_dict.insert(8, s.clone());
+            }
+            _ => {}
+        };
+    });
+    // 3 is the string where (x - strings(1)).len()  == 1 and x set in
+    let three_string = input
+        .iter()
+        .find(|s| s.len() == 5 && digit_contains(s, value_digit_dict.get(&1).unwrap()))
+        .ok_or_else(|| AocError::solver("Unable to deduce digit three"))?;
+    digit_value_dict.insert(three_string.into(), 3);
+    value_digit_dict.insert(3, three_string.into());
+    // 9 is where 8 - 4 - 3 and 4 in x_6 and 3 in x_6
+    let nine_string = input
+        .iter()
+        .find(|s| {
+            s.len() == 6
+                && digit_contains(s, value_digit_dict.get(&3).unwrap())
+                && digit_contains(s, value_digit_dict.get(&4).unwrap())
+        })
+        .ok_or_else(|| AocError::solver("Unable to deduce digit nine"))?;
+    digit_value_dict.insert(nine_string.into(), 9);
+    value_digit_dict.insert(9, nine_string.into());
+    let e_true = subtract_string(
+        subtract_string(
+            value_digit_dict.get(&8).unwrap().into(),
+            value_digit_dict.get(&4).unwrap().into(),
+        ),
+        value_digit_dict.get(&3).unwrap().into(),
+    );
+    // 2 is where |x| = 5 and x contains e_true and x is not 3
+    let two_string = input
+        .iter()
+        .find(|s| {
+            s.len() == 5 && *s != value_digit_dict.get(&3).unwrap() && digit_contains(s, &e_true)
+        })
+        .ok_or_else(|| AocError::solver("Unable to deduce digit two"))?;
+    digit_value_dict.insert(two_string.into(), 2);
+    value_digit_dict.insert(2, two_string.into());
+    // 5 is last where |x| = 5
+    let five_string = input
+        .iter()
+        .find(|s| {
+            s.len() == 5
+                && *s != value_digit_dict.get(&2).unwrap()
+                && *s != value_digit_dict.get(&3).unwrap()
+        })
+        .ok_or_else(|| AocError::solver("Unable to deduce digit five"))?;
+    digit_value_dict.insert(five_string.into(), 5);
+    // 0 is where 7 is in x_6
+    let zero_string = input
+        .iter()
+        .find(|s| {
+            s.len() == 6
+                && *s != value_digit_dict.get(&9).unwrap()
+                && digit_contains(s, value_digit_dict.get(&7).unwrap())
+        })
+        .ok_or_else(|| AocError::solver("Unable to deduce digit zero"))?;
+    digit_value_dict.insert(zero_string.into(), 0);
+    // 6 remaining
+    let six_string = input
+        .iter()
+        .find(|s| !digit_value_dict.contains_key(*s))
+        .ok_or_else(|| AocError::solver("Unable to deduce digit six"))?;
+    digit_value_dict.insert(six_string.into(), 6);
+    Ok(digit_value_dict)
+}
+
+fn output_to_number(output: &[String], dict: &HashMap<String, i32>) -> Result<i32> {
+    output.iter().try_fold(0i32, |number, s| {
+        let digit = dict
+            .get(s)
+            .ok_or_else(|| AocError::solver(format!("Unknown output digit {}", s)))?;
+        number
+            .checked_mul(10)
+            .and_then(|number| number.checked_add(*digit))
+            .ok_or_else(|| AocError::solver("Output value does not fit an i32"))
+    })
+}
+
+pub fn compute_output_sum(all: &[Vec<String>], outputs: &[Vec<String>]) -> Result<i32> {
+    let dicts: Vec<HashMap<String, i32>> = all
+        .iter()
+        .map(|x| reason_dictionary(x))
+        .collect::<Result<Vec<HashMap<String, i32>>>>()?;
+    let mut result_sum: i32 = 0;
+    for (idx, dict) in dicts.iter().enumerate() {
+        let output = outputs[idx].clone();
+        result_sum = result_sum
+            .checked_add(output_to_number(&output, dict)?)
+            .ok_or_else(|| AocError::solver("Sum of the output values does not fit an i32"))?;
+    }
+    Ok(result_sum)
+}
@@ -0,0 +1,22 @@
+use crate::solution::Solver;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+
+// Every implemented day, in puzzle order
+pub static SOLVERS: &[&dyn Solver] = &[
+    &day1::Day1,
+    &day2::Day2,
+    &day3::Day3,
+    &day4::Day4,
+    &day5::Day5,
+    &day6::Day6,
+    &day7::Day7,
+    &day8::Day8,
+];
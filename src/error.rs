@@ -0,0 +1,123 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+pub type Result<T> = std::result::Result<T, AocError>;
+
+/// Every way reading, parsing or solving a puzzle can fail
+#[derive(Debug)]
+pub enum AocError {
+    /// The input could not be read from `source`
+    Io { source: String, error: io::Error },
+    /// `text` at the 1-based `line` and `column` could not be parsed
+    Parse {
+        line: usize,
+        column: usize,
+        text: String,
+        message: String,
+    },
+    /// The input parsed fine, but does not describe a valid puzzle
+    Validation(String),
+    /// The solver could not find an answer for the given input
+    Solver(String),
+    /// Talking to the puzzle server failed
+    Fetch(String),
+}
+
+impl AocError {
+    pub fn io<S: fmt::Display>(source: S, error: io::Error) -> AocError {
+        AocError::Io {
+            source: source.to_string(),
+            error,
+        }
+    }
+
+    pub fn parse<M: Into<String>>(line: usize, column: usize, text: &str, message: M) -> AocError {
+        AocError::Parse {
+            line,
+            column,
+            text: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Parse error for `field`, which must be a slice of `line`, so the
+    /// column can be derived from its position within the line
+    pub fn parse_field<M: Into<String>>(
+        line_number: usize,
+        line: &str,
+        field: &str,
+        message: M,
+    ) -> AocError {
+        AocError::parse(line_number, column_of(line, field), field, message)
+    }
+
+    pub fn validation<M: Into<String>>(message: M) -> AocError {
+        AocError::Validation(message.into())
+    }
+
+    pub fn solver<M: Into<String>>(message: M) -> AocError {
+        AocError::Solver(message.into())
+    }
+
+    pub fn fetch<M: Into<String>>(message: M) -> AocError {
+        AocError::Fetch(message.into())
+    }
+}
+
+/// Parses `field`, a slice of `line`, reporting its position on failure
+pub fn parse_field<T>(line_number: usize, line: &str, field: &str) -> Result<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    field
+        .parse::<T>()
+        .map_err(|e| AocError::parse_field(line_number, line, field, e.to_string()))
+}
+
+// 1-based column of `field` inside `line`, or 1 if it is not a slice of it
+fn column_of(line: &str, field: &str) -> usize {
+    let line_start = line.as_ptr() as usize;
+    let field_start = field.as_ptr() as usize;
+    if field_start >= line_start && field_start <= line_start + line.len() {
+        line[..field_start - line_start].chars().count() + 1
+    } else {
+        1
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::Io { source, error } => {
+                write!(f, "Unable to read input {}: {}", source, error)
+            }
+            AocError::Parse {
+                line,
+                column,
+                text,
+                message,
+            } => write!(
+                f,
+                "Parse error at line {}, column {}: {} (found {:?})",
+                line, column, message, text
+            ),
+            AocError::Validation(message) => write!(f, "Invalid input: {}", message),
+            AocError::Solver(message) => write!(f, "Solver failed: {}", message),
+            AocError::Fetch(message) => {
+                write!(f, "Fetching from the puzzle server failed: {}", message)
+            }
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
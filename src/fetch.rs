@@ -0,0 +1,239 @@
+use crate::error::{AocError, Result};
+use std::cell::OnceCell;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const AOC_URL: &str = "https://adventofcode.com";
+
+// The puzzle server asks automated tools to identify themselves
+const USER_AGENT: &str = "github.com/Hyde46/advent_of_code_2021";
+
+/// Where puzzle inputs and texts are downloaded from. Tests swap the real
+/// server for a local stand-in by pointing `HttpBackend` at it.
+pub trait FetchBackend {
+    fn fetch_input(&self, year: u32, day: u32) -> Result<String>;
+    /// The puzzle description as HTML, which contains the example input
+    fn fetch_puzzle(&self, year: u32, day: u32) -> Result<String>;
+}
+
+/// Talks to the puzzle server over HTTP, authenticated with a session token
+pub struct HttpBackend {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl HttpBackend {
+    pub fn new(base_url: &str, session: &str) -> HttpBackend {
+        HttpBackend {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Uses the `AOC_SESSION` environment variable or the `session` file in
+    /// the config directory, and `AOC_URL` to override the server
+    pub fn from_env() -> Result<HttpBackend> {
+        let session = match env::var("AOC_SESSION") {
+            Ok(session) => session,
+            Err(_) => {
+                let path = config_dir().join("session");
+                fs::read_to_string(&path).map_err(|_| {
+                    AocError::fetch(format!(
+                        "No session token, set AOC_SESSION or write it to {}",
+                        path.display()
+                    ))
+                })?
+            }
+        };
+        let base_url = env::var("AOC_URL").unwrap_or_else(|_| AOC_URL.to_string());
+        Ok(HttpBackend::new(&base_url, &session))
+    }
+
+    fn get(&self, path: &str) -> Result<String> {
+        let url = format!("{}{}", self.base_url, path);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+        read_response(&url, response)
+    }
+
+    pub(crate) fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String> {
+        let url = format!("{}{}", self.base_url, path);
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(form);
+        read_response(&url, response)
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: std::result::Result<ureq::Response, ureq::Error>,
+) -> Result<String> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|error| AocError::io(url, error)),
+        Err(ureq::Error::Status(code, _)) => Err(AocError::fetch(format!(
+            "{} responded with status {}",
+            url, code
+        ))),
+        Err(error) => Err(AocError::fetch(format!("{}: {}", url, error))),
+    }
+}
+
+impl FetchBackend for HttpBackend {
+    fn fetch_input(&self, year: u32, day: u32) -> Result<String> {
+        self.get(&format!("/{}/day/{}/input", year, day))
+    }
+
+    fn fetch_puzzle(&self, year: u32, day: u32) -> Result<String> {
+        self.get(&format!("/{}/day/{}", year, day))
+    }
+}
+
+/// An `HttpBackend` set up from the environment on the first download, so
+/// inputs that are already cached can be read without a session token
+#[derive(Default)]
+pub struct LazyHttpBackend {
+    backend: OnceCell<HttpBackend>,
+}
+
+impl LazyHttpBackend {
+    fn backend(&self) -> Result<&HttpBackend> {
+        if let Some(backend) = self.backend.get() {
+            return Ok(backend);
+        }
+        let backend = HttpBackend::from_env()?;
+        Ok(self.backend.get_or_init(|| backend))
+    }
+}
+
+impl FetchBackend for LazyHttpBackend {
+    fn fetch_input(&self, year: u32, day: u32) -> Result<String> {
+        self.backend()?.fetch_input(year, day)
+    }
+
+    fn fetch_puzzle(&self, year: u32, day: u32) -> Result<String> {
+        self.backend()?.fetch_puzzle(year, day)
+    }
+}
+
+/// Per user store of downloaded inputs, laid out like `src/inputs` with a
+/// directory per year. Anything already cached is never fetched again.
+pub struct InputCache<B: FetchBackend> {
+    dir: PathBuf,
+    backend: B,
+}
+
+impl<B: FetchBackend> InputCache<B> {
+    pub fn new(dir: PathBuf, backend: B) -> InputCache<B> {
+        InputCache { dir, backend }
+    }
+
+    pub fn input_path(&self, year: u32, day: u32) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day-{}.txt", day))
+    }
+
+    pub fn example_path(&self, year: u32, day: u32) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day-{}-example.txt", day))
+    }
+
+    /// Path of the cached input, fetching it first if needed
+    pub fn input(&self, year: u32, day: u32) -> Result<PathBuf> {
+        let path = self.input_path(year, day);
+        if !path.exists() {
+            let input = self.backend.fetch_input(year, day)?;
+            self.store(&path, &input)?;
+        }
+        Ok(path)
+    }
+
+    /// Path of the cached example, extracted from the puzzle text if needed
+    pub fn example(&self, year: u32, day: u32) -> Result<PathBuf> {
+        let path = self.example_path(year, day);
+        if !path.exists() {
+            let puzzle = self.backend.fetch_puzzle(year, day)?;
+            let example = extract_example(&puzzle).ok_or_else(|| {
+                AocError::fetch(format!(
+                    "No example found in the puzzle text of {} day {}",
+                    year, day
+                ))
+            })?;
+            self.store(&path, &example)?;
+        }
+        Ok(path)
+    }
+
+    // Writes through a temporary file, so an interrupted download is never
+    // mistaken for a cached input
+    fn store(&self, path: &Path, content: &str) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|error| AocError::io(dir.display(), error))?;
+        }
+        let partial = path.with_extension("part");
+        fs::write(&partial, content).map_err(|error| AocError::io(partial.display(), error))?;
+        fs::rename(&partial, path).map_err(|error| AocError::io(path.display(), error))
+    }
+}
+
+/// The first `<pre><code>` block of a puzzle text, which holds the example
+pub fn extract_example(html: &str) -> Option<String> {
+    let start = html.find("<pre><code>")? + "<pre><code>".len();
+    let end = start + html[start..].find("</code></pre>")?;
+    Some(unescape_html(&strip_tags(&html[start..end])))
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn unescape_html(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// `AOC_CACHE_DIR`, or `aoc` in the user's cache directory
+pub fn default_cache_dir() -> PathBuf {
+    if let Some(dir) = env::var_os("AOC_CACHE_DIR") {
+        return PathBuf::from(dir);
+    }
+    user_dir("XDG_CACHE_HOME", ".cache").join("aoc")
+}
+
+/// `aoc` in the user's config directory, holding the session token
+pub fn config_dir() -> PathBuf {
+    user_dir("XDG_CONFIG_HOME", ".config").join("aoc")
+}
+
+fn user_dir(xdg_var: &str, home_fallback: &str) -> PathBuf {
+    match (env::var_os(xdg_var), env::var_os("HOME")) {
+        (Some(dir), _) => PathBuf::from(dir),
+        (None, Some(home)) => PathBuf::from(home).join(home_fallback),
+        (None, None) => env::temp_dir(),
+    }
+}
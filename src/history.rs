@@ -0,0 +1,227 @@
+use crate::bench::format_duration;
+use crate::dates;
+use crate::days;
+use crate::error::{AocError, Result};
+use crate::runner::PartResult;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// One solved part, as remembered across runs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Unix time in seconds
+    pub timestamp: u64,
+    /// Short hash of the checked out commit, with a `+` for local changes
+    pub commit: Option<String>,
+    #[serde(default = "days::first_year")]
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub duration_ns: u128,
+    pub input_hash: String,
+}
+
+impl HistoryEntry {
+    pub fn from_result(
+        result: &PartResult,
+        year: u32,
+        input_hash: &str,
+        commit: Option<String>,
+    ) -> Self {
+        HistoryEntry {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            commit,
+            year,
+            day: result.day,
+            part: result.part,
+            answer: result.answer.clone(),
+            duration_ns: result.duration_ns,
+            input_hash: input_hash.to_string(),
+        }
+    }
+}
+
+/// 64 bit FNV-1a, stable across runs and platforms unlike `DefaultHasher`
+pub fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+pub fn input_hash(raw_input: &str) -> String {
+    format!("{:016x}", fnv1a(raw_input.as_bytes()))
+}
+
+/// The commit checked out in `dir`, or `None` outside of a git checkout
+pub fn git_commit(dir: &Path) -> Option<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["rev-parse", "--short=10", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let mut commit = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    let clean = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(["diff", "--quiet", "HEAD"])
+        .status()
+        .map_or(true, |status| status.success());
+    if !clean {
+        commit.push('+');
+    }
+    Some(commit)
+}
+
+/// Append-only JSON-lines file of every recorded result
+pub struct History {
+    path: PathBuf,
+}
+
+impl History {
+    pub fn new(path: PathBuf) -> History {
+        History { path }
+    }
+
+    pub fn append(&self, entries: &[HistoryEntry]) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|error| AocError::io(dir.display(), error))?;
+        }
+        let mut lines = String::new();
+        for entry in entries {
+            lines.push_str(&serde_json::to_string(entry).expect("Entries are valid JSON"));
+            lines.push('\n');
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(lines.as_bytes()))
+            .map_err(|error| AocError::io(self.path.display(), error))
+    }
+
+    /// Every entry in the order it was recorded, none if nothing was yet
+    pub fn load(&self) -> Result<Vec<HistoryEntry>> {
+        let content = match fs::read_to_string(&self.path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(error) => return Err(AocError::io(self.path.display(), error)),
+        };
+        content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                serde_json::from_str(line)
+                    .map_err(|e| AocError::parse(i + 1, 1, line, format!("Invalid entry: {}", e)))
+            })
+            .collect()
+    }
+}
+
+// UTC date and time down to the minute
+fn format_timestamp(timestamp: u64) -> String {
+    let (year, month, day) = dates::civil_from_days((timestamp / 86_400) as i64);
+    let seconds = timestamp % 86_400;
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds / 60 % 60
+    )
+}
+
+fn percent_change(previous: u128, current: u128) -> String {
+    if previous == 0 {
+        return String::new();
+    }
+    format!(
+        "{:+.1}%",
+        (current as f64 - previous as f64) / previous as f64 * 100.0
+    )
+}
+
+/// Runs of one day, grouped by part. Timings and answers are compared to the
+/// previous run on the same input, flagging answers that changed.
+pub fn render_history(entries: &[HistoryEntry], year: u32, day: u32) -> String {
+    let mut parts: Vec<u32> = entries
+        .iter()
+        .filter(|e| e.year == year && e.day == day)
+        .map(|e| e.part)
+        .collect();
+    parts.sort_unstable();
+    parts.dedup();
+    if parts.is_empty() {
+        return format!("No runs of {} day {} recorded yet\n", year, day);
+    }
+    let mut report = String::new();
+    for part in parts {
+        let runs: Vec<&HistoryEntry> = entries
+            .iter()
+            .filter(|e| e.year == year && e.day == day && e.part == part)
+            .collect();
+        report.push_str(&format!(
+            "{} day {} part {}\n{:<18}{:<13}{:<10}{:>16}{:>12}  {}\n",
+            year, day, part, "When", "Commit", "Input", "Answer", "Time", "Change"
+        ));
+        let mut answer_changes = 0;
+        for (i, run) in runs.iter().enumerate() {
+            let previous = runs[..i]
+                .iter()
+                .rev()
+                .find(|p| p.input_hash == run.input_hash);
+            let change = match previous {
+                None => "new input".to_string(),
+                Some(previous) if previous.answer != run.answer => {
+                    answer_changes += 1;
+                    format!(
+                        "{} ANSWER CHANGED (was {})",
+                        percent_change(previous.duration_ns, run.duration_ns),
+                        previous.answer
+                    )
+                }
+                Some(previous) => percent_change(previous.duration_ns, run.duration_ns),
+            };
+            report.push_str(&format!(
+                "{:<18}{:<13}{:<10}{:>16}{:>12}  {}\n",
+                format_timestamp(run.timestamp),
+                run.commit.as_deref().unwrap_or("-"),
+                &run.input_hash[..run.input_hash.len().min(8)],
+                run.answer,
+                format_duration(Duration::from_nanos(run.duration_ns as u64)),
+                change.trim()
+            ));
+        }
+        // Trend over the runs on the most recent input
+        let latest = runs[runs.len() - 1];
+        let same_input: Vec<&&HistoryEntry> = runs
+            .iter()
+            .filter(|r| r.input_hash == latest.input_hash)
+            .collect();
+        let first = same_input[0];
+        report.push_str(&format!(
+            "Runs: {}, answer changes: {}, time on the latest input: {} -> {} ({})\n\n",
+            runs.len(),
+            answer_changes,
+            format_duration(Duration::from_nanos(first.duration_ns as u64)),
+            format_duration(Duration::from_nanos(latest.duration_ns as u64)),
+            match percent_change(first.duration_ns, latest.duration_ns).as_str() {
+                "" => "n/a",
+                change => change,
+            }
+        ));
+    }
+    report
+}
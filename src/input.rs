@@ -0,0 +1,68 @@
+use crate::error::{AocError, Result};
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Where a puzzle input is read from
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl InputSource {
+    /// `-` reads from stdin, anything else is treated as a file path
+    pub fn from_arg(arg: &str) -> InputSource {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn default_for_day(year: u32, day: u32) -> InputSource {
+        InputSource::File(default_input_path(year, day))
+    }
+
+    pub fn read(&self) -> Result<String> {
+        let result = match self {
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut buffer = String::new();
+                io::stdin().read_to_string(&mut buffer).map(|_| buffer)
+            }
+            InputSource::Inline(data) => Ok(data.clone()),
+        };
+        result.map_err(|error| AocError::io(self, error))
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::Inline(_) => write!(f, "<inline>"),
+        }
+    }
+}
+
+/// Inputs live next to the sources, so resolve them against the crate
+/// manifest instead of the current working directory
+pub fn default_input_path(year: u32, day: u32) -> PathBuf {
+    inputs_dir(year).join(format!("day-{}.txt", day))
+}
+
+/// The example from the puzzle text, stored next to the real input
+pub fn example_input_path(year: u32, day: u32) -> PathBuf {
+    inputs_dir(year).join(format!("day-{}-example.txt", day))
+}
+
+/// Inputs are kept apart per year, in `src/inputs/<year>`
+pub fn inputs_dir(year: u32) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src")
+        .join("inputs")
+        .join(year.to_string())
+}
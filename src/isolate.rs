@@ -0,0 +1,168 @@
+use crate::error::{AocError, Result};
+use std::fmt;
+use std::io::Read;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Bounds for a child process
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Limits {
+    /// Wall-clock time before the child is killed
+    pub timeout: Duration,
+    /// Address space cap in bytes, only enforced on unix
+    pub memory_bytes: Option<u64>,
+}
+
+impl Default for Limits {
+    fn default() -> Limits {
+        Limits {
+            timeout: Duration::from_secs(60),
+            memory_bytes: None,
+        }
+    }
+}
+
+/// How an isolated child ended
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChildOutcome {
+    Finished {
+        stdout: String,
+    },
+    /// Exited on its own with a failure code, e.g. for a solver error
+    Failed {
+        code: i32,
+        stderr: String,
+    },
+    /// Killed by a signal, which includes aborting on a failed allocation
+    Crashed {
+        status: String,
+        stderr: String,
+    },
+    TimedOut {
+        after: Duration,
+        stderr: String,
+    },
+}
+
+impl fmt::Display for ChildOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (summary, stderr) = match self {
+            ChildOutcome::Finished { .. } => return write!(f, "finished"),
+            ChildOutcome::Failed { code, stderr } => (format!("exited with code {}", code), stderr),
+            ChildOutcome::Crashed { status, stderr } => (format!("crashed ({})", status), stderr),
+            ChildOutcome::TimedOut { after, stderr } => {
+                (format!("timed out after {:?}", after), stderr)
+            }
+        };
+        // Kept on one line for the summary table
+        let stderr: Vec<&str> = stderr
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .collect();
+        match stderr.is_empty() {
+            true => write!(f, "{}", summary),
+            false => write!(f, "{}: {}", summary, stderr.join("; ")),
+        }
+    }
+}
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Runs `command` within `limits`, capturing its output. The child is killed
+/// once the timeout passes.
+pub fn run_limited(mut command: Command, limits: &Limits) -> Result<ChildOutcome> {
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Some(bytes) = limits.memory_bytes {
+        limit_memory(&mut command, bytes);
+    }
+    let program = format!("{:?}", command.get_program());
+    let mut child = command
+        .spawn()
+        .map_err(|error| AocError::io(&program, error))?;
+    // Read both pipes while waiting, so a chatty child can not block on them
+    let stdout = read_pipe(child.stdout.take());
+    let stderr = read_pipe(child.stderr.take());
+
+    let start = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait().map_err(|e| AocError::io(&program, e))? {
+            break Some(status);
+        }
+        if start.elapsed() >= limits.timeout {
+            kill(&mut child).map_err(|e| AocError::io(&program, e))?;
+            break None;
+        }
+        thread::sleep(POLL_INTERVAL);
+    };
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+    Ok(match status {
+        None => ChildOutcome::TimedOut {
+            after: limits.timeout,
+            stderr,
+        },
+        Some(status) if status.success() => ChildOutcome::Finished { stdout },
+        Some(status) => match status.code() {
+            Some(code) => ChildOutcome::Failed { code, stderr },
+            None => ChildOutcome::Crashed {
+                status: describe_signal(&status),
+                stderr,
+            },
+        },
+    })
+}
+
+fn read_pipe<R: Read + Send + 'static>(pipe: Option<R>) -> JoinHandle<String> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buffer);
+        }
+        String::from_utf8_lossy(&buffer).to_string()
+    })
+}
+
+fn kill(child: &mut Child) -> std::io::Result<()> {
+    child.kill()?;
+    child.wait().map(|_| ())
+}
+
+#[cfg(unix)]
+fn limit_memory(command: &mut Command, bytes: u64) {
+    use std::os::unix::process::CommandExt;
+    let limit = libc::rlimit {
+        rlim_cur: bytes as libc::rlim_t,
+        rlim_max: bytes as libc::rlim_t,
+    };
+    // Only async-signal-safe calls are allowed between fork and exec
+    unsafe {
+        command.pre_exec(move || {
+            if libc::setrlimit(libc::RLIMIT_AS, &limit) != 0 {
+                return Err(std::io::Error::last_os_error());
+            }
+            Ok(())
+        });
+    }
+}
+
+#[cfg(not(unix))]
+fn limit_memory(_: &mut Command, _: u64) {}
+
+#[cfg(unix)]
+fn describe_signal(status: &ExitStatus) -> String {
+    use std::os::unix::process::ExitStatusExt;
+    match status.signal() {
+        Some(signal) => format!("signal {}", signal),
+        None => status.to_string(),
+    }
+}
+
+#[cfg(not(unix))]
+fn describe_signal(status: &ExitStatus) -> String {
+    status.to_string()
+}
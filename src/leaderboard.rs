@@ -0,0 +1,232 @@
+use crate::days;
+use crate::error::{AocError, Result};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+/// A private leaderboard, as exported by `/2021/leaderboard/private/view/<id>.json`
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    pub event: String,
+    pub owner_id: u64,
+    pub members: HashMap<String, Member>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    /// Day -> star level -> when the star was collected
+    pub completion_day_level: HashMap<String, HashMap<String, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    pub get_star_ts: u64,
+}
+
+impl Member {
+    /// Unnamed members are shown the way the leaderboard page shows them
+    pub fn display_name(&self) -> String {
+        match &self.name {
+            Some(name) => name.clone(),
+            None => format!("(anonymous user #{})", self.id),
+        }
+    }
+
+    /// Star timestamps keyed by day and level
+    pub fn stars(&self) -> Result<BTreeMap<(u32, u32), u64>> {
+        let mut stars = BTreeMap::new();
+        for (day, levels) in self.completion_day_level.iter() {
+            let day = parse_key(day, days::LAST_DAY, "day")?;
+            for (level, star) in levels.iter() {
+                let level = parse_key(level, 2, "star level")?;
+                stars.insert((day, level), star.get_star_ts);
+            }
+        }
+        Ok(stars)
+    }
+}
+
+fn parse_key(key: &str, max: u32, what: &str) -> Result<u32> {
+    match key.parse::<u32>() {
+        Ok(value) if (1..=max).contains(&value) => Ok(value),
+        _ => Err(AocError::validation(format!(
+            "Leaderboard has an invalid {} {:?}",
+            what, key
+        ))),
+    }
+}
+
+impl Leaderboard {
+    pub fn from_json(json: &str) -> Result<Leaderboard> {
+        serde_json::from_str(json)
+            .map_err(|e| AocError::validation(format!("Malformed leaderboard: {}", e)))
+    }
+
+    pub fn load(path: &Path) -> Result<Leaderboard> {
+        let json = fs::read_to_string(path).map_err(|error| AocError::io(path.display(), error))?;
+        Leaderboard::from_json(&json)
+    }
+}
+
+// Timestamp, member id for ties and position in the member list
+type Finisher = (u64, u64, usize);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Standing {
+    pub name: String,
+    pub local_score: u32,
+    pub stars: u32,
+    /// Seconds between the first and second star, per day with both stars
+    pub deltas: BTreeMap<u32, u64>,
+}
+
+/// Members ordered by local score. Every star is worth as many points as
+/// there are members, minus one for each member who got it earlier.
+pub fn standings(leaderboard: &Leaderboard) -> Result<Vec<Standing>> {
+    let members: Vec<&Member> = leaderboard.members.values().collect();
+    let stars = members
+        .iter()
+        .map(|member| member.stars())
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut finishers: BTreeMap<(u32, u32), Vec<Finisher>> = BTreeMap::new();
+    for (index, member_stars) in stars.iter().enumerate() {
+        for (key, timestamp) in member_stars.iter() {
+            finishers
+                .entry(*key)
+                .or_default()
+                .push((*timestamp, members[index].id, index));
+        }
+    }
+    let mut scores = vec![0; members.len()];
+    for finished in finishers.values_mut() {
+        finished.sort_unstable();
+        for (rank, (_, _, index)) in finished.iter().enumerate() {
+            scores[*index] += (members.len() - rank) as u32;
+        }
+    }
+
+    let mut standings: Vec<Standing> = members
+        .iter()
+        .zip(stars.iter())
+        .zip(scores)
+        .map(|((member, member_stars), local_score)| {
+            let deltas = member_stars
+                .iter()
+                .filter(|((_, level), _)| *level == 2)
+                .filter_map(|((day, _), second)| {
+                    let first = member_stars.get(&(*day, 1))?;
+                    Some((*day, second.saturating_sub(*first)))
+                })
+                .collect();
+            Standing {
+                name: member.display_name(),
+                local_score,
+                stars: member_stars.len() as u32,
+                deltas,
+            }
+        })
+        .collect();
+    standings.sort_by(|a, b| {
+        b.local_score
+            .cmp(&a.local_score)
+            .then(b.stars.cmp(&a.stars))
+            .then(a.name.cmp(&b.name))
+    });
+    Ok(standings)
+}
+
+pub fn format_delta(seconds: u64) -> String {
+    if seconds < 60 * 60 {
+        format!("{}m{:02}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{}h{:02}m", seconds / (60 * 60), seconds / 60 % 60)
+    }
+}
+
+// Every day on which anyone got both stars
+fn delta_days(standings: &[Standing]) -> Vec<u32> {
+    let mut days: Vec<u32> = standings
+        .iter()
+        .flat_map(|s| s.deltas.keys().copied())
+        .collect();
+    days.sort_unstable();
+    days.dedup();
+    days
+}
+
+fn delta_cell(standing: &Standing, day: u32) -> String {
+    standing
+        .deltas
+        .get(&day)
+        .map_or("-".to_string(), |delta| format_delta(*delta))
+}
+
+pub fn render_table(standings: &[Standing]) -> String {
+    let days = delta_days(standings);
+    let name_width = standings
+        .iter()
+        .map(|s| s.name.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(4)
+        + 2;
+    let mut table = format!(
+        "{:<6}{:<width$}{:>7}{:>7}",
+        "Rank",
+        "Name",
+        "Score",
+        "Stars",
+        width = name_width
+    );
+    for day in days.iter() {
+        table.push_str(&format!("{:>9}", format!("Day {}", day)));
+    }
+    table.push('\n');
+    for (rank, standing) in standings.iter().enumerate() {
+        table.push_str(&format!(
+            "{:<6}{:<width$}{:>7}{:>7}",
+            rank + 1,
+            standing.name,
+            standing.local_score,
+            standing.stars,
+            width = name_width
+        ));
+        for day in days.iter() {
+            table.push_str(&format!("{:>9}", delta_cell(standing, *day)));
+        }
+        table.push('\n');
+    }
+    table
+}
+
+/// Day columns hold the time from the first to the second star
+pub fn render_markdown(standings: &[Standing]) -> String {
+    let days = delta_days(standings);
+    let mut table = String::from("| Rank | Name | Score | Stars |");
+    for day in days.iter() {
+        table.push_str(&format!(" Day {} |", day));
+    }
+    table.push_str("\n|---:|---|---:|---:|");
+    table.push_str(&"---:|".repeat(days.len()));
+    table.push('\n');
+    for (rank, standing) in standings.iter().enumerate() {
+        table.push_str(&format!(
+            "| {} | {} | {} | {} |",
+            rank + 1,
+            standing.name.replace('|', "\\|"),
+            standing.local_score,
+            standing.stars
+        ));
+        for day in days.iter() {
+            table.push_str(&format!(" {} |", delta_cell(standing, *day)));
+        }
+        table.push('\n');
+    }
+    table
+}
@@ -0,0 +1,25 @@
+pub mod accounts;
+pub mod bench;
+pub mod dashboard;
+pub mod dates;
+pub mod days;
+pub mod error;
+pub mod fetch;
+pub mod history;
+pub mod input;
+pub mod isolate;
+pub mod leaderboard;
+pub mod memory;
+pub mod readme;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod sonar;
+pub mod submit;
+pub mod tokenizer;
+pub mod watch;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: memory::CountingAllocator = memory::CountingAllocator;
@@ -1,3 +1,12 @@
+mod cli;
+
+use std::env;
+use std::process;
+
 fn main() {
-    println!("Hello, advent of code 2021!");
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Err(e) = cli::dispatch(&args) {
+        eprintln!("{}", e);
+        process::exit(2);
+    }
 }
@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// Heap usage of one stage, on the thread that ran it
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct AllocStats {
+    /// Most bytes live at once, above what was live before the stage
+    pub peak_bytes: usize,
+    /// Allocations and reallocations
+    pub allocations: usize,
+}
+
+thread_local! {
+    static CURRENT: Cell<usize> = const { Cell::new(0) };
+    static PEAK: Cell<usize> = const { Cell::new(0) };
+    static COUNT: Cell<usize> = const { Cell::new(0) };
+}
+
+/// The system allocator, counting live bytes and allocations per thread.
+/// Installed as the global allocator by the `alloc-stats` feature.
+pub struct CountingAllocator;
+
+fn record_alloc(size: usize) {
+    let _ = CURRENT.try_with(|current| {
+        let live = current.get() + size;
+        current.set(live);
+        let _ = PEAK.try_with(|peak| peak.set(peak.get().max(live)));
+    });
+    let _ = COUNT.try_with(|count| count.set(count.get() + 1));
+}
+
+// Memory freed on another thread than it was allocated on can make the
+// count drop below zero
+fn record_free(size: usize) {
+    let _ = CURRENT.try_with(|current| current.set(current.get().saturating_sub(size)));
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_free(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_free(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+pub fn enabled() -> bool {
+    cfg!(feature = "alloc-stats")
+}
+
+/// Runs `stage`, reporting its heap usage if the counting allocator is
+/// installed
+pub fn measure<R>(stage: impl FnOnce() -> R) -> (R, Option<AllocStats>) {
+    if !enabled() {
+        return (stage(), None);
+    }
+    let base = CURRENT.with(Cell::get);
+    let count = COUNT.with(Cell::get);
+    // Keep the enclosing peak intact for nested measurements
+    let outer_peak = PEAK.with(|peak| peak.replace(base));
+    let result = stage();
+    let peak = PEAK.with(|peak| {
+        let stage_peak = peak.get();
+        peak.set(stage_peak.max(outer_peak));
+        stage_peak
+    });
+    let stats = AllocStats {
+        peak_bytes: peak.saturating_sub(base),
+        allocations: COUNT.with(Cell::get) - count,
+    };
+    (result, Some(stats))
+}
+
+pub fn format_bytes(bytes: usize) -> String {
+    let bytes = bytes as f64;
+    if bytes < 1024.0 {
+        format!("{} B", bytes)
+    } else if bytes < 1024.0 * 1024.0 {
+        format!("{:.1} KiB", bytes / 1024.0)
+    } else {
+        format!("{:.1} MiB", bytes / (1024.0 * 1024.0))
+    }
+}
@@ -0,0 +1,102 @@
+use crate::days;
+use crate::error::{AocError, Result};
+use std::collections::BTreeMap;
+
+// The generated list of days sits between these lines of the Readme
+pub const DAYS_START: &str = "<!-- days:start -->";
+pub const DAYS_END: &str = "<!-- days:end -->";
+
+/// Notes written after each day's link, keyed by year and day
+type DayNotes = BTreeMap<(u32, u32), String>;
+
+// Start and end line of the generated section
+fn section(lines: &[&str]) -> Result<(usize, usize)> {
+    let start = lines
+        .iter()
+        .position(|l| l.trim() == DAYS_START)
+        .ok_or_else(|| AocError::validation(format!("Readme has no {} line", DAYS_START)))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|l| l.trim() == DAYS_END)
+            .ok_or_else(|| AocError::validation(format!("Readme has no {} line", DAYS_END)))?;
+    Ok((start, end))
+}
+
+fn parse_notes(section: &[&str]) -> DayNotes {
+    let mut notes = DayNotes::new();
+    let mut year = None;
+    for line in section {
+        let line = line.trim_start();
+        if let Some(heading) = line.strip_prefix("### ") {
+            year = heading.trim().parse::<u32>().ok();
+        } else if let (Some(year), Some(link)) = (year, line.strip_prefix("[Day ")) {
+            let day = link.split(']').next().and_then(|d| d.parse::<u32>().ok());
+            let note = link.find(')').map_or("", |end| &link[end + 1..]);
+            if let Some(day) = day {
+                notes.insert((year, day), note.to_string());
+            }
+        }
+    }
+    notes
+}
+
+fn render_section(notes: &DayNotes) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current_year = None;
+    for ((year, day), note) in notes.iter() {
+        if current_year != Some(*year) {
+            if current_year.is_some() {
+                lines.push(String::new());
+            }
+            lines.push(format!("### {}", year));
+            current_year = Some(*year);
+        }
+        lines.push(String::new());
+        lines.push(format!(
+            " [Day {}]({}){}",
+            day,
+            days::source_url(*year, *day),
+            note
+        ));
+    }
+    lines
+}
+
+// Replaces the section with the days picked by `select` from the ones
+// already listed
+fn rewrite(readme: &str, select: impl FnOnce(DayNotes) -> DayNotes) -> Result<String> {
+    let lines: Vec<&str> = readme.lines().collect();
+    let (start, end) = section(&lines)?;
+    let notes = select(parse_notes(&lines[start + 1..end]));
+    let mut updated: Vec<String> = lines[..=start].iter().map(|l| l.to_string()).collect();
+    updated.extend(render_section(&notes));
+    updated.extend(lines[end..].iter().map(|l| l.to_string()));
+    Ok(updated.join("\n") + "\n")
+}
+
+/// Lists exactly `days` (year and day pairs), keeping the notes of days
+/// that were listed before
+pub fn update_days(readme: &str, days: &[(u32, u32)]) -> Result<String> {
+    rewrite(readme, |old| {
+        days.iter()
+            .map(|key| (*key, old.get(key).cloned().unwrap_or_default()))
+            .collect()
+    })
+}
+
+/// Adds a link to one more day, in order of year and day
+pub fn add_day(readme: &str, year: u32, day: u32) -> Result<String> {
+    rewrite(readme, |mut notes| {
+        notes.entry((year, day)).or_default();
+        notes
+    })
+}
+
+/// Every registered day, for `update_days`
+pub fn registered_days() -> Vec<(u32, u32)> {
+    days::YEARS
+        .iter()
+        .flat_map(|(year, solvers)| solvers.iter().map(move |s| (*year, s.day())))
+        .collect()
+}
@@ -0,0 +1,125 @@
+use crate::bench::format_duration;
+use crate::memory::format_bytes;
+use crate::runner::{JobReport, PartResult};
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OutputFormat {
+    Plain,
+    Json,
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<OutputFormat, String> {
+        match s {
+            "plain" => Ok(OutputFormat::Plain),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(format!("Unknown format {}, expected json, csv or plain", s)),
+        }
+    }
+}
+
+pub fn render(results: &[PartResult], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Plain => results
+            .iter()
+            .map(|r| match (r.parse_memory, r.memory) {
+                (Some(parse), Some(part)) => format!(
+                    "Day {} part {}: {} (parse peak {} in {} allocations, part peak {} in {} allocations)\n",
+                    r.day,
+                    r.part,
+                    r.answer,
+                    format_bytes(parse.peak_bytes),
+                    parse.allocations,
+                    format_bytes(part.peak_bytes),
+                    part.allocations
+                ),
+                _ => format!("Day {} part {}: {}\n", r.day, r.part, r.answer),
+            })
+            .collect(),
+        OutputFormat::Json => {
+            let mut json = serde_json::to_string_pretty(results).expect("Results are valid JSON");
+            json.push('\n');
+            json
+        }
+        OutputFormat::Csv => {
+            // Memory columns only exist when the counting allocator does
+            let with_memory = results.iter().any(|r| r.memory.is_some());
+            let mut csv = String::from("day,part,answer,duration_ns,input_path");
+            if with_memory {
+                csv.push_str(",parse_peak_bytes,parse_allocations,peak_bytes,allocations");
+            }
+            csv.push('\n');
+            for r in results {
+                csv.push_str(&format!(
+                    "{},{},{},{},{}",
+                    r.day,
+                    r.part,
+                    csv_field(&r.answer),
+                    r.duration_ns,
+                    csv_field(&r.input_path)
+                ));
+                if with_memory {
+                    let parse = r.parse_memory.unwrap_or_default();
+                    let memory = r.memory.unwrap_or_default();
+                    csv.push_str(&format!(
+                        ",{},{},{},{}",
+                        parse.peak_bytes, parse.allocations, memory.peak_bytes, memory.allocations
+                    ));
+                }
+                csv.push('\n');
+            }
+            csv
+        }
+    }
+}
+
+/// One row per part of a parallel run, failures included
+pub fn render_summary(reports: &[JobReport]) -> String {
+    let mut table = format!(
+        "{:<5}{:<6}{:>16}{:>12}  {}\n",
+        "Day", "Part", "Answer", "Time", "Status"
+    );
+    for report in reports {
+        let (answer, time, status) = match &report.outcome {
+            Ok(result) => (
+                result.answer.clone(),
+                format_duration(Duration::from_nanos(result.duration_ns as u64)),
+                "ok".to_string(),
+            ),
+            Err(e) => ("-".to_string(), "-".to_string(), format!("failed: {}", e)),
+        };
+        table.push_str(&format!(
+            "{:<5}{:<6}{:>16}{:>12}  {}\n",
+            report.day, report.part, answer, time, status
+        ));
+    }
+    table
+}
+
+/// The summary table for plain output. Machine readable formats only hold
+/// the parts that succeeded, like `render`, so callers report failures apart.
+pub fn render_reports(reports: &[JobReport], format: OutputFormat) -> String {
+    if format == OutputFormat::Plain {
+        return render_summary(reports);
+    }
+    let results: Vec<PartResult> = reports
+        .iter()
+        .filter_map(|report| report.outcome.clone().ok())
+        .collect();
+    render(&results, format)
+}
+
+// Quotes fields containing separators, quotes or newlines as per RFC 4180
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
@@ -0,0 +1,128 @@
+use crate::error::Result;
+use crate::input::InputSource;
+use crate::memory::{self, AllocStats};
+use crate::solution::Solver;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Instant;
+
+/// The answer to one part of a day, as reported by the runner
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PartResult {
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    /// Time spent solving the part, not counting parsing
+    pub duration_ns: u128,
+    pub input_path: String,
+    /// Heap usage while solving the part, with the `alloc-stats` feature
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<AllocStats>,
+    /// Heap usage while parsing the input the part was solved on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parse_memory: Option<AllocStats>,
+}
+
+/// Reads and parses the input once, then solves each of `parts` on it
+pub fn solve(solver: &dyn Solver, source: &InputSource, parts: &[u32]) -> Result<Vec<PartResult>> {
+    solve_raw(solver, &source.read()?, &source.to_string(), parts)
+}
+
+/// Like `solve`, for an input which was already read from `input_path`
+pub fn solve_raw(
+    solver: &dyn Solver,
+    raw_input: &str,
+    input_path: &str,
+    parts: &[u32],
+) -> Result<Vec<PartResult>> {
+    let (input, parse_memory) = memory::measure(|| solver.parse_input(raw_input));
+    let input = input?;
+    let mut results = Vec::new();
+    for part in parts {
+        let start = Instant::now();
+        let (answer, memory) = memory::measure(|| solver.solve_part(input.as_ref(), *part));
+        let answer = answer?;
+        results.push(PartResult {
+            day: solver.day(),
+            part: *part,
+            answer,
+            duration_ns: start.elapsed().as_nanos(),
+            input_path: input_path.to_string(),
+            memory,
+            parse_memory,
+        });
+    }
+    Ok(results)
+}
+
+/// One part of a day to solve on the thread pool
+pub struct Job<'a> {
+    pub solver: &'a dyn Solver,
+    pub source: InputSource,
+    pub part: u32,
+}
+
+/// How a job ended. Errors and panics are kept as messages, so one failing
+/// day does not take the others down.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JobReport {
+    pub day: u32,
+    pub part: u32,
+    pub outcome: std::result::Result<PartResult, String>,
+}
+
+/// Solves every job on `threads` workers, each parsing its own input.
+/// Reports are sorted by day and part.
+pub fn solve_parallel(jobs: &[Job], threads: usize) -> Vec<JobReport> {
+    let mut reports = solve_jobs(jobs, threads);
+    reports.sort_by_key(|report| (report.day, report.part));
+    reports
+}
+
+/// Like `solve_parallel`, with one report per job in the order of `jobs`
+pub fn solve_jobs(jobs: &[Job], threads: usize) -> Vec<JobReport> {
+    let next = AtomicUsize::new(0);
+    let reports = Mutex::new(Vec::with_capacity(jobs.len()));
+    thread::scope(|scope| {
+        for _ in 0..threads.max(1).min(jobs.len()) {
+            scope.spawn(|| loop {
+                let index = next.fetch_add(1, Ordering::SeqCst);
+                let job = match jobs.get(index) {
+                    Some(job) => job,
+                    None => break,
+                };
+                let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+                    solve(job.solver, &job.source, &[job.part])
+                }));
+                let outcome = match outcome {
+                    Ok(Ok(mut results)) => Ok(results.remove(0)),
+                    Ok(Err(e)) => Err(e.to_string()),
+                    Err(payload) => Err(format!("Panicked: {}", panic_message(payload.as_ref()))),
+                };
+                let report = JobReport {
+                    day: job.solver.day(),
+                    part: job.part,
+                    outcome,
+                };
+                reports.lock().unwrap().push((index, report));
+            });
+        }
+    });
+    let mut reports = reports.into_inner().unwrap();
+    reports.sort_by_key(|(index, _)| *index);
+    reports.into_iter().map(|(_, report)| report).collect()
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown cause"
+    }
+}
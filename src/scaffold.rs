@@ -0,0 +1,264 @@
+use crate::days;
+use crate::error::{AocError, Result};
+use crate::readme;
+use regex::Regex;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Generates everything a new day needs inside the project at `root`: the
+/// solver module wired into the registry, empty input files, an ignored
+/// example test and a Readme link. The first day of a year also registers
+/// the year. Nothing is written unless every file can be updated. Returns
+/// every file created or changed.
+pub fn scaffold_day(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>> {
+    if !(1..=days::LAST_DAY).contains(&day) {
+        return Err(AocError::validation(format!(
+            "Day {} is not a puzzle day, expected 1 to {}",
+            day,
+            days::LAST_DAY
+        )));
+    }
+    let days_dir = root.join("src").join("days");
+    let year_dir = days_dir.join(format!("y{}", year));
+    let module_path = year_dir.join(format!("day{}.rs", day));
+    if module_path.exists() {
+        return Err(AocError::validation(format!(
+            "{} day {} already exists at {}",
+            year,
+            day,
+            module_path.display()
+        )));
+    }
+    // Every edit is worked out first, so a file missing its marker leaves
+    // the project untouched
+    let mut writes = Vec::new();
+
+    let year_registry_path = year_dir.join("mod.rs");
+    if year_registry_path.exists() {
+        let registry = read_file(&year_registry_path)?;
+        writes.push((year_registry_path, register_day(&registry, day)?));
+    } else {
+        let registry_path = days_dir.join("mod.rs");
+        let registry = read_file(&registry_path)?;
+        writes.push((registry_path, register_year(&registry, year)?));
+        writes.push((year_registry_path, year_template(day)));
+    }
+
+    writes.push((module_path, solver_template(day)));
+
+    let inputs = root.join("src").join("inputs").join(year.to_string());
+    for name in [
+        format!("day-{}.txt", day),
+        format!("day-{}-example.txt", day),
+    ]
+    .iter()
+    {
+        let path = inputs.join(name);
+        // Never clobber an input that was already downloaded
+        if !path.exists() {
+            writes.push((path, String::new()));
+        }
+    }
+
+    let tests_path = root.join("tests").join("regression.rs");
+    let tests = read_file(&tests_path)?;
+    writes.push((tests_path, add_example_test(&tests, year, day)?));
+
+    let readme_path = root.join("Readme.md");
+    let readme = read_file(&readme_path)?;
+    writes.push((readme_path, readme::add_day(&readme, year, day)?));
+
+    let mut touched = Vec::new();
+    for (path, content) in writes {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|error| AocError::io(dir.display(), error))?;
+        }
+        write_file(&path, &content)?;
+        touched.push(path);
+    }
+    Ok(touched)
+}
+
+fn solver_template(day: u32) -> String {
+    format!(
+        r#"use crate::error::{{AocError, Result}};
+use crate::solution::Solution;
+use crate::tokenizer::non_blank_lines;
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    const DAY: u32 = {day};
+    type Input = Vec<String>;
+    type Answer = i32;
+
+    fn parse(input: &str) -> Result<Vec<String>> {{
+        Ok(non_blank_lines(input)
+            .map(|line| line.text.to_string())
+            .collect())
+    }}
+
+    fn part1(_input: &Vec<String>) -> Result<i32> {{
+        Err(AocError::solver("Part 1 is not solved yet"))
+    }}
+
+    fn part2(_input: &Vec<String>) -> Result<i32> {{
+        Err(AocError::solver("Part 2 is not solved yet"))
+    }}
+}}
+"#,
+        day = day
+    )
+}
+
+fn year_template(day: u32) -> String {
+    format!(
+        r#"use crate::solution::Solver;
+
+pub mod day{day};
+
+// Every implemented day, in puzzle order
+{solvers}
+"#,
+        day = day,
+        solvers = render_solvers(&[day])
+    )
+}
+
+const YEARS_HEAD: &str = "pub static YEARS: &[(u32, &[&dyn Solver])] = &[";
+const SOLVERS_HEAD: &str = "pub static SOLVERS: &[&dyn Solver] = &[";
+
+// rustfmt keeps an array on one line while its entries and the whole line
+// stay within these widths, and puts every entry on its own line otherwise
+const MAX_WIDTH: usize = 100;
+const ARRAY_WIDTH: usize = 60;
+
+// Declares the year's module and regenerates `YEARS` with it, laid out the
+// way rustfmt would
+fn register_year(registry: &str, year: u32) -> Result<String> {
+    let registry = declare_module(registry, "y", &format!("y{}", year))
+        .ok_or_else(|| AocError::validation("No year modules found in the registry"))?;
+    let (start, end) = find_static(&registry, "YEARS")?;
+    let entry = Regex::new(r"\((\d+), y\d+::SOLVERS\)").unwrap();
+    let mut years: Vec<u32> = entry
+        .captures_iter(&registry[start..end])
+        .filter_map(|captures| captures[1].parse().ok())
+        .collect();
+    years.push(year);
+    years.sort_unstable();
+    years.dedup();
+    let entries: Vec<String> = years
+        .iter()
+        .map(|year| format!("({0}, y{0}::SOLVERS)", year))
+        .collect();
+    Ok(format!(
+        "{}{}{}",
+        &registry[..start],
+        render_array(YEARS_HEAD, &entries),
+        &registry[end..]
+    ))
+}
+
+// Adds `pub mod <name>;` to the block of modules starting with `prefix`,
+// sorted by name like rustfmt sorts them, so `day10` comes before `day2`
+fn declare_module(registry: &str, prefix: &str, name: &str) -> Option<String> {
+    let mut lines: Vec<String> = registry.lines().map(|l| l.to_string()).collect();
+    let declaration = format!("pub mod {}", prefix);
+    let start = lines.iter().position(|l| l.starts_with(&declaration))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .take_while(|l| l.starts_with(&declaration))
+            .count();
+    let mut modules: Vec<String> = lines
+        .drain(start..end)
+        .map(|l| l["pub mod ".len()..].trim_end_matches(';').to_string())
+        .collect();
+    modules.push(name.to_string());
+    modules.sort();
+    modules.dedup();
+    let declarations = modules.iter().map(|module| format!("pub mod {};", module));
+    lines.splice(start..start, declarations);
+    Some(lines.join("\n") + "\n")
+}
+
+// `head` is the statement up to and including the opening `&[`
+fn render_array(head: &str, entries: &[String]) -> String {
+    let one_line = format!("{}{}];", head, entries.join(", "));
+    if entries.join(", ").len() <= ARRAY_WIDTH && one_line.len() <= MAX_WIDTH {
+        return one_line;
+    }
+    let mut out = head.to_string();
+    for entry in entries {
+        out += &format!("\n    {},", entry);
+    }
+    out + "\n];"
+}
+
+// Start and end of the `name` static in `registry`, including its `];`
+fn find_static(registry: &str, name: &str) -> Result<(usize, usize)> {
+    let start = registry
+        .find(&format!("pub static {}", name))
+        .ok_or_else(|| AocError::validation(format!("No {} found in the registry", name)))?;
+    let end = start
+        + registry[start..].find("];").ok_or_else(|| {
+            AocError::validation(format!("{} is not closed in the registry", name))
+        })?
+        + 2;
+    Ok((start, end))
+}
+
+fn render_solvers(days: &[u32]) -> String {
+    let entries: Vec<String> = days
+        .iter()
+        .map(|day| format!("&day{0}::Day{0}", day))
+        .collect();
+    render_array(SOLVERS_HEAD, &entries)
+}
+
+// Declares the day's module and regenerates `SOLVERS` with the new solver,
+// in puzzle order
+fn register_day(registry: &str, day: u32) -> Result<String> {
+    let registry = declare_module(registry, "day", &format!("day{}", day))
+        .ok_or_else(|| AocError::validation("No day modules found in the registry"))?;
+    let (start, end) = find_static(&registry, "SOLVERS")?;
+    let entry = Regex::new(r"&day(\d+)::Day\d+").unwrap();
+    let mut days: Vec<u32> = entry
+        .captures_iter(&registry[start..end])
+        .filter_map(|captures| captures[1].parse().ok())
+        .collect();
+    days.push(day);
+    days.sort_unstable();
+    days.dedup();
+    Ok(format!(
+        "{}{}{}",
+        &registry[..start],
+        render_solvers(&days),
+        &registry[end..]
+    ))
+}
+
+fn add_example_test(tests: &str, year: u32, day: u32) -> Result<String> {
+    let mut lines: Vec<String> = tests.lines().map(|l| l.to_string()).collect();
+    let last_test = lines
+        .iter()
+        .rposition(|l| l.starts_with("example_test!("))
+        .ok_or_else(|| AocError::validation("No example tests found"))?;
+    lines.insert(
+        last_test + 1,
+        format!(
+            "// TODO: Fill in the example answers from the puzzle text and drop the ignore\n\
+             example_test!(#[ignore] y{0}_day{1}_example, {0}, {1}, \"?\", \"?\");",
+            year, day
+        ),
+    );
+    Ok(lines.join("\n") + "\n")
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|error| AocError::io(path.display(), error))
+}
+
+fn write_file(path: &Path, content: &str) -> Result<()> {
+    fs::write(path, content).map_err(|error| AocError::io(path.display(), error))
+}
@@ -0,0 +1,55 @@
+use crate::error::{AocError, Result};
+use std::any::Any;
+use std::fmt::Display;
+
+/// A day's puzzle, split into a parse stage and the two puzzle parts.
+/// Parsing happens once and the parsed input is shared by both parts.
+pub trait Solution {
+    const DAY: u32;
+    type Input: 'static;
+    type Answer: Display;
+    /// Files under `src` the solution is built from besides the day's own
+    /// module, so watch mode re-runs the day when they change
+    const EXTRA_SOURCES: &'static [&'static str] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Self::Answer>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer>;
+}
+
+/// Object safe view of a `Solution`, so every day can live in one registry
+pub trait Solver: Sync {
+    fn day(&self) -> u32;
+    fn extra_sources(&self) -> &'static [&'static str];
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any>>;
+    fn solve_part(&self, input: &dyn Any, part: u32) -> Result<String>;
+}
+
+impl<S: Solution + Sync> Solver for S {
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn extra_sources(&self) -> &'static [&'static str] {
+        S::EXTRA_SOURCES
+    }
+
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve_part(&self, input: &dyn Any, part: u32) -> Result<String> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("Input was parsed by a different solver");
+        match part {
+            1 => S::part1(input).map(|answer| answer.to_string()),
+            2 => S::part2(input).map(|answer| answer.to_string()),
+            _ => Err(AocError::validation(format!(
+                "Day {} has no part {}",
+                S::DAY,
+                part
+            ))),
+        }
+    }
+}
@@ -0,0 +1,565 @@
+use crate::dates;
+use crate::error::{parse_field, AocError, Result};
+use crate::tokenizer::{fields, non_blank_lines, InputLine};
+use stats::{MinMax, OnlineStats};
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+use std::io::BufRead;
+
+/// How a sonar log is laid out
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SonarFormat {
+    /// One bare depth per line
+    Depths,
+    /// `timestamp,depth[,sensor_id]` rows, optionally below a header
+    Csv,
+}
+
+impl SonarFormat {
+    /// Picks the format from the first non blank line of a log
+    pub fn detect(line: &str) -> SonarFormat {
+        if line.contains(',') {
+            SonarFormat::Csv
+        } else {
+            SonarFormat::Depths
+        }
+    }
+}
+
+/// Readings of one sensor, in the order they were taken
+#[derive(Debug, Clone, PartialEq)]
+pub struct SensorLog {
+    /// `None` for depths without a sensor id
+    pub sensor: Option<String>,
+    pub readings: Vec<u32>,
+}
+
+impl SensorLog {
+    pub fn label(&self) -> &str {
+        sensor_label(&self.sensor)
+    }
+}
+
+fn sensor_label(sensor: &Option<String>) -> &str {
+    sensor.as_deref().unwrap_or("-")
+}
+
+/// Parses a log in `format`, or the detected one, grouping readings by
+/// sensor. Sensors are sorted by id, readings without one come first.
+pub fn parse_log(input: &str, format: Option<SonarFormat>) -> Result<Vec<SensorLog>> {
+    let mut parser = LogParser::new(format);
+    let mut sensors: BTreeMap<Option<String>, Vec<u32>> = BTreeMap::new();
+    for line in non_blank_lines(input) {
+        if let Some((sensor, depth)) = parser.parse_line(line)? {
+            let sensor = sensor.map(|id| id.to_string());
+            sensors.entry(sensor).or_default().push(depth);
+        }
+    }
+    Ok(sensors
+        .into_iter()
+        .map(|(sensor, readings)| SensorLog { sensor, readings })
+        .collect())
+}
+
+// Turns non blank lines into sensor and depth pairs, detecting the format
+// on the first line and checking that every sensor's clock moves forward
+struct LogParser {
+    format: Option<SonarFormat>,
+    first_line: bool,
+    clocks: BTreeMap<Option<String>, i64>,
+}
+
+impl LogParser {
+    fn new(format: Option<SonarFormat>) -> LogParser {
+        LogParser {
+            format,
+            first_line: true,
+            clocks: BTreeMap::new(),
+        }
+    }
+
+    // `None` for a CSV header
+    fn parse_line<'a>(&mut self, line: InputLine<'a>) -> Result<Option<(Option<&'a str>, u32)>> {
+        let first_line = std::mem::replace(&mut self.first_line, false);
+        let format = *self
+            .format
+            .get_or_insert_with(|| SonarFormat::detect(line.text));
+        if format == SonarFormat::Depths {
+            let depth = parse_field::<u32>(line.number, line.text, line.text.trim())?;
+            return Ok(Some((None, depth)));
+        }
+        let row: Vec<&str> = fields(line.text, ',').collect();
+        if first_line && row[0].eq_ignore_ascii_case("timestamp") {
+            return Ok(None);
+        }
+        if row.len() < 2 || row.len() > 3 {
+            return Err(AocError::parse(
+                line.number,
+                1,
+                line.text,
+                "Expected timestamp,depth[,sensor_id]",
+            ));
+        }
+        let timestamp = parse_timestamp(row[0]).ok_or_else(|| {
+            AocError::parse_field(
+                line.number,
+                line.text,
+                row[0],
+                "Expected Unix seconds or YYYY-MM-DDTHH:MM:SS",
+            )
+        })?;
+        let depth = parse_field::<u32>(line.number, line.text, row[1])?;
+        let sensor = row.get(2).copied().filter(|id| !id.is_empty());
+        let clock = sensor.map(|id| id.to_string());
+        if let Some(previous) = self.clocks.get(&clock) {
+            if timestamp <= *previous {
+                return Err(AocError::parse_field(
+                    line.number,
+                    line.text,
+                    row[0],
+                    format!(
+                        "Timestamp is not after the previous reading of sensor {}",
+                        sensor_label(&clock)
+                    ),
+                ));
+            }
+        }
+        self.clocks.insert(clock, timestamp);
+        Ok(Some((sensor, depth)))
+    }
+}
+
+/// Seconds since the Unix epoch, given as is or as a UTC
+/// `YYYY-MM-DDTHH:MM:SS` date, optionally followed by `Z`
+pub fn parse_timestamp(text: &str) -> Option<i64> {
+    if let Ok(seconds) = text.parse::<i64>() {
+        return Some(seconds);
+    }
+    let text = text.strip_suffix('Z').unwrap_or(text);
+    let (date, time) = text.split_once(['T', ' '])?;
+    let date: Vec<i64> = date
+        .split('-')
+        .map(|f| f.parse().ok())
+        .collect::<Option<_>>()?;
+    let time: Vec<i64> = time
+        .split(':')
+        .map(|f| f.parse().ok())
+        .collect::<Option<_>>()?;
+    match (date.as_slice(), time.as_slice()) {
+        ([year, month, day], [hour, minute, second])
+            if (0..24).contains(hour) && (0..60).contains(minute) && (0..=60).contains(second) =>
+        {
+            let days = dates::days_from_civil(*year, *month, *day)?;
+            Some(days * 86_400 + hour * 3600 + minute * 60 + second)
+        }
+        _ => None,
+    }
+}
+
+/// Counts how often the sum of `width` consecutive readings grows from one
+/// window to the next. Neighbouring windows share all but their first and
+/// last reading, so only those two need comparing.
+pub fn count_window_increases(readings: &[u32], width: usize) -> usize {
+    readings
+        .iter()
+        .zip(readings.iter().skip(width))
+        .filter(|(first, last)| last > first)
+        .count()
+}
+
+/// What a `SonarAnalyzer` has seen so far
+#[derive(Debug, Clone, PartialEq)]
+pub struct SonarSummary {
+    pub width: usize,
+    pub readings: u64,
+    pub increases: u64,
+    pub min: Option<u32>,
+    pub max: Option<u32>,
+    pub mean: Option<f64>,
+}
+
+impl fmt::Display for SonarSummary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Readings: {}", self.readings)?;
+        writeln!(
+            f,
+            "Window increases (width {}): {}",
+            self.width, self.increases
+        )?;
+        if let (Some(min), Some(max), Some(mean)) = (self.min, self.max, self.mean) {
+            writeln!(f, "Depth: min {}, max {}, mean {:.2}", min, max, mean)?;
+        }
+        Ok(())
+    }
+}
+
+/// Counts window increases over readings fed one at a time, for logs too
+/// large to load at once. Only the last `width` readings are kept.
+pub struct SonarAnalyzer {
+    width: usize,
+    window: VecDeque<u32>,
+    readings: u64,
+    increases: u64,
+    min: Option<u32>,
+    max: Option<u32>,
+    sum: u128,
+}
+
+impl SonarAnalyzer {
+    pub fn new(width: usize) -> SonarAnalyzer {
+        SonarAnalyzer {
+            width,
+            // Grows with the readings, so huge widths cost nothing up front
+            window: VecDeque::new(),
+            readings: 0,
+            increases: 0,
+            min: None,
+            max: None,
+            sum: 0,
+        }
+    }
+
+    pub fn push(&mut self, reading: u32) {
+        // Same identity as `count_window_increases`: the reading leaving the
+        // window is compared with the one entering it
+        if self.window.len() == self.width {
+            if let Some(first) = self.window.pop_front() {
+                if reading > first {
+                    self.increases += 1;
+                }
+            }
+        }
+        if self.width > 0 {
+            self.window.push_back(reading);
+        }
+        self.readings += 1;
+        self.min = Some(self.min.map_or(reading, |min| min.min(reading)));
+        self.max = Some(self.max.map_or(reading, |max| max.max(reading)));
+        self.sum += u128::from(reading);
+    }
+
+    /// Feeds every reading of `reader`, one per line, skipping blank lines.
+    /// `source` names the reader in errors.
+    pub fn read<R: BufRead>(&mut self, reader: R, source: &str) -> Result<()> {
+        for_each_line(reader, source, |line| {
+            self.push(parse_field::<u32>(
+                line.number,
+                line.text,
+                line.text.trim(),
+            )?);
+            Ok(())
+        })
+    }
+
+    pub fn summary(&self) -> SonarSummary {
+        SonarSummary {
+            width: self.width,
+            readings: self.readings,
+            increases: self.increases,
+            min: self.min,
+            max: self.max,
+            mean: match self.readings {
+                0 => None,
+                n => Some(self.sum as f64 / n as f64),
+            },
+        }
+    }
+}
+
+// Calls `f` with every non blank line of `reader`, reusing one buffer
+fn for_each_line<R, F>(mut reader: R, source: &str, mut f: F) -> Result<()>
+where
+    R: BufRead,
+    F: FnMut(InputLine) -> Result<()>,
+{
+    let mut buffer = String::new();
+    let mut number = 0;
+    loop {
+        buffer.clear();
+        let read = reader
+            .read_line(&mut buffer)
+            .map_err(|error| AocError::io(source, error))?;
+        if read == 0 {
+            return Ok(());
+        }
+        number += 1;
+        let text = buffer.trim_end_matches(['\n', '\r']);
+        if !text.trim().is_empty() {
+            f(InputLine { number, text })?;
+        }
+    }
+}
+
+/// Streams a log in either format through one `SonarAnalyzer` per sensor
+pub struct SensorAnalyzers {
+    width: usize,
+    parser: LogParser,
+    sensors: BTreeMap<Option<String>, SonarAnalyzer>,
+}
+
+impl SensorAnalyzers {
+    /// Detects the format from the first line when `format` is `None`
+    pub fn new(width: usize, format: Option<SonarFormat>) -> SensorAnalyzers {
+        SensorAnalyzers {
+            width,
+            parser: LogParser::new(format),
+            sensors: BTreeMap::new(),
+        }
+    }
+
+    pub fn read<R: BufRead>(&mut self, reader: R, source: &str) -> Result<()> {
+        for_each_line(reader, source, |line| {
+            if let Some((sensor, depth)) = self.parser.parse_line(line)? {
+                let width = self.width;
+                self.sensors
+                    .entry(sensor.map(|id| id.to_string()))
+                    .or_insert_with(|| SonarAnalyzer::new(width))
+                    .push(depth);
+            }
+            Ok(())
+        })
+    }
+
+    /// One summary per sensor, ordered like `parse_log`
+    pub fn summaries(&self) -> Vec<(Option<String>, SonarSummary)> {
+        self.sensors
+            .iter()
+            .map(|(sensor, analyzer)| (sensor.clone(), analyzer.summary()))
+            .collect()
+    }
+}
+
+// Quantiles reported next to the median
+pub const QUANTILES: [f64; 4] = [0.1, 0.25, 0.75, 0.9];
+
+/// Summary statistics of a series of values
+#[derive(Debug, Clone, PartialEq)]
+pub struct Distribution {
+    pub count: usize,
+    pub min: i64,
+    pub max: i64,
+    pub mean: f64,
+    /// Population variance
+    pub variance: f64,
+    pub stddev: f64,
+    pub median: f64,
+    /// The value at each of `QUANTILES`
+    pub quantiles: Vec<f64>,
+}
+
+impl Distribution {
+    /// `None` for an empty series
+    pub fn of(values: &[i64]) -> Option<Distribution> {
+        let online = OnlineStats::from_slice(values);
+        let mut min_max = MinMax::new();
+        values.iter().for_each(|value| min_max.add(*value));
+        let mut sorted = values.to_vec();
+        sorted.sort_unstable();
+        Some(Distribution {
+            count: values.len(),
+            min: *min_max.min()?,
+            max: *min_max.max()?,
+            mean: online.mean(),
+            variance: online.variance(),
+            stddev: online.stddev(),
+            median: quantile(&sorted, 0.5),
+            quantiles: QUANTILES.iter().map(|q| quantile(&sorted, *q)).collect(),
+        })
+    }
+}
+
+// Linear interpolation between the closest ranks of non empty `sorted`
+fn quantile(sorted: &[i64], q: f64) -> f64 {
+    let rank = q * (sorted.len() - 1) as f64;
+    let below = sorted[rank.floor() as usize] as f64;
+    let above = sorted[rank.ceil() as usize] as f64;
+    below + (above - below) * rank.fract()
+}
+
+/// Shape of a depth profile, beyond the number of increases
+#[derive(Debug, Clone, PartialEq)]
+pub struct DepthStats {
+    pub readings: Distribution,
+    /// Differences between neighbouring readings, `None` for a single one
+    pub differences: Option<Distribution>,
+    /// Most readings in a row that each go deeper than the one before
+    pub longest_increase: usize,
+    /// Most readings in a row that each go shallower than the one before
+    pub longest_decrease: usize,
+    /// Stretches of equal neighbouring readings, which count as neither
+    /// increases nor decreases
+    pub plateaus: usize,
+}
+
+/// `None` without any readings
+pub fn depth_stats(readings: &[u32]) -> Option<DepthStats> {
+    let values: Vec<i64> = readings.iter().map(|r| i64::from(*r)).collect();
+    let differences: Vec<i64> = values.windows(2).map(|w| w[1] - w[0]).collect();
+    let mut longest_increase = 1;
+    let mut longest_decrease = 1;
+    let mut plateaus = 0;
+    let (mut increase, mut decrease) = (1, 1);
+    for (i, difference) in differences.iter().enumerate() {
+        increase = if *difference > 0 { increase + 1 } else { 1 };
+        decrease = if *difference < 0 { decrease + 1 } else { 1 };
+        longest_increase = longest_increase.max(increase);
+        longest_decrease = longest_decrease.max(decrease);
+        if *difference == 0 && (i == 0 || differences[i - 1] != 0) {
+            plateaus += 1;
+        }
+    }
+    Some(DepthStats {
+        readings: Distribution::of(&values)?,
+        differences: Distribution::of(&differences),
+        longest_increase,
+        longest_decrease,
+        plateaus,
+    })
+}
+
+// Statistics shown for every series, before the quantiles
+type Row = (&'static str, fn(&Distribution) -> String);
+const ROWS: [Row; 7] = [
+    ("count", |d| d.count.to_string()),
+    ("min", |d| d.min.to_string()),
+    ("max", |d| d.max.to_string()),
+    ("mean", |d| format!("{:.2}", d.mean)),
+    ("variance", |d| format!("{:.2}", d.variance)),
+    ("stddev", |d| format!("{:.2}", d.stddev)),
+    ("median", |d| format!("{:.2}", d.median)),
+];
+
+/// One column per series, one row per statistic
+pub fn render_stats(stats: &DepthStats) -> String {
+    let mut columns = vec![("readings", &stats.readings)];
+    if let Some(differences) = &stats.differences {
+        columns.push(("differences", differences));
+    }
+    let mut out = format!("{:<10}", "");
+    for (name, _) in columns.iter() {
+        out += &format!(" {:>12}", name);
+    }
+    out.push('\n');
+    for (name, cell) in ROWS.iter() {
+        out += &format!("{:<10}", name);
+        for (_, distribution) in columns.iter() {
+            out += &format!(" {:>12}", cell(distribution));
+        }
+        out.push('\n');
+    }
+    for (i, q) in QUANTILES.iter().enumerate() {
+        out += &format!("{:<10}", format!("p{}", q * 100.0));
+        for (_, distribution) in columns.iter() {
+            out += &format!(" {:>12.2}", distribution.quantiles[i]);
+        }
+        out.push('\n');
+    }
+    out += &format!(
+        "Longest increasing run: {} readings\n",
+        stats.longest_increase
+    );
+    out += &format!(
+        "Longest decreasing run: {} readings\n",
+        stats.longest_decrease
+    );
+    out += &format!("Plateaus: {}\n", stats.plateaus);
+    out
+}
+
+// Size of the SVG chart and the margin left for the axis labels
+const SVG_WIDTH: f64 = 800.0;
+const SVG_HEIGHT: f64 = 300.0;
+const SVG_MARGIN: f64 = 40.0;
+
+/// Line chart of depth against reading index, with depth growing downwards
+/// like the seafloor. Readings that make the sum of a `width` window grow
+/// are marked in red.
+pub fn render_svg(readings: &[u32], width: usize) -> String {
+    let min = readings.iter().min().copied().unwrap_or(0);
+    let max = readings.iter().max().copied().unwrap_or(0);
+    let plot_width = SVG_WIDTH - 2.0 * SVG_MARGIN;
+    let plot_height = SVG_HEIGHT - 2.0 * SVG_MARGIN;
+    let x = |i: usize| SVG_MARGIN + plot_width * i as f64 / (readings.len().max(2) - 1) as f64;
+    let y = |depth: u32| {
+        SVG_MARGIN + plot_height * f64::from(depth - min) / f64::from((max - min).max(1))
+    };
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\">\n",
+        SVG_WIDTH, SVG_HEIGHT
+    );
+    svg += &format!(
+        "<rect width=\"{}\" height=\"{}\" fill=\"white\"/>\n",
+        SVG_WIDTH, SVG_HEIGHT
+    );
+    svg += &format!(
+        "<text x=\"{}\" y=\"{}\" font-size=\"12\">{}</text>\n",
+        SVG_MARGIN,
+        SVG_MARGIN - 8.0,
+        min
+    );
+    svg += &format!(
+        "<text x=\"{}\" y=\"{}\" font-size=\"12\">{}</text>\n",
+        SVG_MARGIN,
+        SVG_HEIGHT - SVG_MARGIN + 16.0,
+        max
+    );
+    svg += &format!(
+        "<text x=\"{}\" y=\"{}\" font-size=\"12\" text-anchor=\"end\">{} readings, window of {}</text>\n",
+        SVG_WIDTH - SVG_MARGIN,
+        SVG_HEIGHT - SVG_MARGIN + 16.0,
+        readings.len(),
+        width
+    );
+    let points: Vec<String> = readings
+        .iter()
+        .enumerate()
+        .map(|(i, depth)| format!("{:.1},{:.1}", x(i), y(*depth)))
+        .collect();
+    svg += &format!(
+        "<polyline fill=\"none\" stroke=\"steelblue\" stroke-width=\"1\" points=\"{}\"/>\n",
+        points.join(" ")
+    );
+    let windows = readings.iter().zip(readings.iter().skip(width));
+    for (i, (first, last)) in windows.enumerate() {
+        if last > first {
+            svg += &format!(
+                "<circle cx=\"{:.1}\" cy=\"{:.1}\" r=\"1.5\" fill=\"red\"/>\n",
+                x(i + width),
+                y(*last)
+            );
+        }
+    }
+    svg += "</svg>\n";
+    svg
+}
+
+const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// One bar per column, averaging the readings that fall into it, so deeper
+/// stretches show as taller bars
+pub fn render_sparkline(readings: &[u32], columns: usize) -> String {
+    let columns = columns.max(1).min(readings.len());
+    let means: Vec<f64> = (0..columns)
+        .map(|column| {
+            let bucket = &readings
+                [column * readings.len() / columns..(column + 1) * readings.len() / columns];
+            bucket.iter().map(|r| f64::from(*r)).sum::<f64>() / bucket.len() as f64
+        })
+        .collect();
+    let min = means.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = means.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let mut line: String = means
+        .iter()
+        .map(|mean| {
+            let level = if max > min {
+                ((mean - min) / (max - min) * (SPARKS.len() - 1) as f64).round() as usize
+            } else {
+                0
+            };
+            SPARKS[level]
+        })
+        .collect();
+    line.push('\n');
+    line
+}
@@ -0,0 +1,236 @@
+use crate::days;
+use crate::error::{AocError, Result};
+use crate::fetch::HttpBackend;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Where answers are submitted to, so a `Submitter` can be driven by
+/// something other than the puzzle server
+pub trait SubmitBackend {
+    /// Returns the HTML response describing the verdict
+    fn submit_answer(&self, year: u32, day: u32, part: u32, answer: &str) -> Result<String>;
+}
+
+impl SubmitBackend for HttpBackend {
+    fn submit_answer(&self, year: u32, day: u32, part: u32, answer: &str) -> Result<String> {
+        let level = part.to_string();
+        self.post_form(
+            &format!("/{}/day/{}/answer", year, day),
+            &[("level", &level), ("answer", answer)],
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// An answer was given too recently, nothing was checked
+    RateLimited,
+    /// The part was already solved, nothing was checked
+    AlreadySolved,
+    Unknown(String),
+}
+
+impl Verdict {
+    fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Incorrect | Verdict::TooHigh | Verdict::TooLow
+        )
+    }
+}
+
+/// Reads the verdict and how long to wait before the next submission from
+/// the server's response
+pub fn parse_response(html: &str) -> (Verdict, Option<u64>) {
+    let verdict = if html.contains("That's the right answer") {
+        Verdict::Correct
+    } else if html.contains("You gave an answer too recently") {
+        Verdict::RateLimited
+    } else if html.contains("Did you already complete it") {
+        Verdict::AlreadySolved
+    } else if html.contains("your answer is too high") {
+        Verdict::TooHigh
+    } else if html.contains("your answer is too low") {
+        Verdict::TooLow
+    } else if html.contains("That's not the right answer") {
+        Verdict::Incorrect
+    } else {
+        let text = Regex::new(r"<[^>]*>").unwrap().replace_all(html, "");
+        Verdict::Unknown(text.split_whitespace().collect::<Vec<&str>>().join(" "))
+    };
+    (verdict, parse_wait(html))
+}
+
+fn parse_wait(html: &str) -> Option<u64> {
+    let left = Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+    if let Some(cap) = left.captures(html) {
+        let minutes = cap
+            .get(1)
+            .map_or(0, |m| m.as_str().parse::<u64>().unwrap_or(0));
+        let seconds = cap[2].parse::<u64>().unwrap_or(0);
+        return Some(minutes * 60 + seconds);
+    }
+    let minutes = Regex::new(r"[Pp]lease wait (one|\d+) minutes? before trying again").unwrap();
+    minutes.captures(html).map(|cap| match &cap[1] {
+        "one" => 60,
+        n => n.parse::<u64>().unwrap_or(1) * 60,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Submission {
+    #[serde(default = "days::first_year")]
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix timestamps in seconds
+    pub submitted_at: u64,
+    pub retry_after: Option<u64>,
+}
+
+/// Every submission made so far, kept in a JSON file so answers known to be
+/// wrong are never sent twice
+#[derive(Debug)]
+pub struct SubmissionLog {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl SubmissionLog {
+    pub fn open(path: PathBuf) -> Result<SubmissionLog> {
+        let submissions = match fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content).map_err(|e| {
+                AocError::validation(format!("Corrupt submission log {}: {}", path.display(), e))
+            })?,
+            // Anything else would have `record` overwrite the known answers
+            Err(error) if error.kind() == ErrorKind::NotFound => Vec::new(),
+            Err(error) => return Err(AocError::io(path.display(), error)),
+        };
+        Ok(SubmissionLog { path, submissions })
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    fn for_part(&self, year: u32, day: u32, part: u32) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |s| s.year == year && s.day == day && s.part == part)
+    }
+
+    /// Why `answer` should not be submitted, if it is already known to fail
+    pub fn check(&self, year: u32, day: u32, part: u32, answer: &str, now: u64) -> Option<String> {
+        if let Some(wait_until) = self.submissions.iter().filter_map(|s| s.retry_after).max() {
+            if wait_until > now {
+                return Some(format!(
+                    "Rate limited, wait {}s before submitting again",
+                    wait_until - now
+                ));
+            }
+        }
+        if let Some(correct) = self
+            .for_part(year, day, part)
+            .find(|s| s.verdict == Verdict::Correct)
+        {
+            return Some(format!(
+                "{} day {} part {} was already solved with {}",
+                year, day, part, correct.answer
+            ));
+        }
+        if let Some(wrong) = self
+            .for_part(year, day, part)
+            .find(|s| s.answer == answer && s.verdict.is_wrong())
+        {
+            return Some(format!(
+                "{} was already rejected as {:?}",
+                answer, wrong.verdict
+            ));
+        }
+        // Too high and too low answers bound every later guess
+        let value = answer.parse::<i64>().ok()?;
+        for submission in self.for_part(year, day, part) {
+            let bound = match submission.answer.parse::<i64>() {
+                Ok(bound) => bound,
+                Err(_) => continue,
+            };
+            match submission.verdict {
+                Verdict::TooHigh if value >= bound => {
+                    return Some(format!("{} is too high, {} already was", answer, bound))
+                }
+                Verdict::TooLow if value <= bound => {
+                    return Some(format!("{} is too low, {} already was", answer, bound))
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    fn record(&mut self, submission: Submission) -> Result<()> {
+        self.submissions.push(submission);
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|error| AocError::io(dir.display(), error))?;
+        }
+        let json = serde_json::to_string_pretty(&self.submissions).expect("Log is valid JSON");
+        fs::write(&self.path, json).map_err(|error| AocError::io(self.path.display(), error))
+    }
+}
+
+pub struct Submitter<B: SubmitBackend> {
+    backend: B,
+    log: SubmissionLog,
+}
+
+impl<B: SubmitBackend> Submitter<B> {
+    pub fn new(backend: B, log: SubmissionLog) -> Submitter<B> {
+        Submitter { backend, log }
+    }
+
+    pub fn log(&self) -> &SubmissionLog {
+        &self.log
+    }
+
+    pub fn submit(&mut self, year: u32, day: u32, part: u32, answer: &str) -> Result<Verdict> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        self.submit_at(year, day, part, answer, now)
+    }
+
+    /// Like `submit`, with `now` as the current unix time in seconds
+    pub fn submit_at(
+        &mut self,
+        year: u32,
+        day: u32,
+        part: u32,
+        answer: &str,
+        now: u64,
+    ) -> Result<Verdict> {
+        if let Some(reason) = self.log.check(year, day, part, answer, now) {
+            return Err(AocError::validation(reason));
+        }
+        let response = self.backend.submit_answer(year, day, part, answer)?;
+        let (verdict, wait) = parse_response(&response);
+        self.log.record(Submission {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict: verdict.clone(),
+            submitted_at: now,
+            retry_after: wait.map(|seconds| now + seconds),
+        })?;
+        Ok(verdict)
+    }
+}
@@ -0,0 +1,157 @@
+use crate::error::{AocError, Result};
+
+/// A single line of puzzle input with its 1-based line number
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InputLine<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+/// Splits `input` into lines, normalising CRLF line endings and ignoring
+/// the trailing newline every downloaded input has. Blank lines in between
+/// are kept, so line numbers match the file.
+pub fn lines(input: &str) -> impl Iterator<Item = InputLine<'_>> {
+    let trimmed = input.trim_end_matches(['\n', '\r']);
+    let mut split = trimmed.split('\n');
+    // An empty input has no lines at all, rather than a single blank one
+    if trimmed.is_empty() {
+        split.next();
+    }
+    split.enumerate().map(|(i, text)| InputLine {
+        number: i + 1,
+        text: text.strip_suffix('\r').unwrap_or(text),
+    })
+}
+
+/// Like `lines`, but skips lines that are empty or only contain whitespace
+pub fn non_blank_lines(input: &str) -> impl Iterator<Item = InputLine<'_>> {
+    lines(input).filter(|line| !line.text.trim().is_empty())
+}
+
+/// Groups lines into records separated by one or more blank lines
+pub fn records(input: &str) -> Vec<Vec<InputLine<'_>>> {
+    let mut records = Vec::new();
+    let mut current = Vec::new();
+    for line in lines(input) {
+        if line.text.trim().is_empty() {
+            if !current.is_empty() {
+                records.push(current);
+                current = Vec::new();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        records.push(current);
+    }
+    records
+}
+
+/// The only non blank line of `input`, for puzzles given as a single line
+pub fn single_line(input: &str) -> Result<InputLine<'_>> {
+    let mut non_blank = non_blank_lines(input);
+    let line = non_blank
+        .next()
+        .ok_or_else(|| AocError::validation("Input is empty"))?;
+    if let Some(extra) = non_blank.next() {
+        return Err(AocError::parse(
+            extra.number,
+            1,
+            extra.text,
+            "Expected the input to be a single line",
+        ));
+    }
+    Ok(line)
+}
+
+/// Splits `text` on `separator` and trims surrounding whitespace from every
+/// field. The fields are slices of `text`, so their columns can be reported.
+pub fn fields(text: &str, separator: char) -> impl Iterator<Item = &str> {
+    text.split(separator).map(|field| field.trim())
+}
+
+/// Whitespace separated fields, collapsing runs of spaces
+pub fn words(text: &str) -> impl Iterator<Item = &str> {
+    text.split_whitespace()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(input: &str) -> Vec<&str> {
+        lines(input).map(|line| line.text).collect()
+    }
+
+    #[test]
+    fn normalises_crlf_line_endings() {
+        assert_eq!(texts("a\r\nb\r\n\r\nc\r\n"), ["a", "b", "", "c"]);
+        // A lone carriage return inside a line is left alone
+        assert_eq!(texts("a\rb\n"), ["a\rb"]);
+    }
+
+    #[test]
+    fn ignores_trailing_newlines() {
+        assert_eq!(texts("a\nb"), ["a", "b"]);
+        assert_eq!(texts("a\nb\n"), ["a", "b"]);
+        assert_eq!(texts("a\nb\n\n\r\n"), ["a", "b"]);
+        // Leading blank lines still count, so numbers match the file
+        let numbers: Vec<usize> = lines("\na\n").map(|line| line.number).collect();
+        assert_eq!(numbers, [1, 2]);
+    }
+
+    #[test]
+    fn empty_input_has_no_lines() {
+        assert!(texts("").is_empty());
+        assert!(texts("\n\r\n").is_empty());
+        assert!(records("").is_empty());
+        assert_eq!(non_blank_lines(" \n\t\n").count(), 0);
+    }
+
+    #[test]
+    fn records_are_separated_by_blank_lines() {
+        let records = records("\na\nb\n\n  \n\nc\n\n");
+        let grouped: Vec<Vec<(usize, &str)>> = records
+            .iter()
+            .map(|record| record.iter().map(|line| (line.number, line.text)).collect())
+            .collect();
+        assert_eq!(grouped, [vec![(2, "a"), (3, "b")], vec![(7, "c")]]);
+    }
+
+    #[test]
+    fn single_line_skips_blank_lines() {
+        let line = single_line("\n3,4,3\n\n").unwrap();
+        assert_eq!(
+            line,
+            InputLine {
+                number: 2,
+                text: "3,4,3"
+            }
+        );
+    }
+
+    #[test]
+    fn single_line_rejects_empty_input() {
+        match single_line(" \n\n") {
+            Err(AocError::Validation(message)) => assert_eq!(message, "Input is empty"),
+            other => panic!("Expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn single_line_reports_the_extra_line() {
+        match single_line("1,2\n\n3,4\n") {
+            Err(AocError::Parse {
+                line,
+                column,
+                text,
+                message,
+            }) => {
+                assert_eq!((line, column, text.as_str()), (3, 1, "3,4"));
+                assert_eq!(message, "Expected the input to be a single line");
+            }
+            other => panic!("Expected a parse error, got {:?}", other),
+        }
+    }
+}
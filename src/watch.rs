@@ -0,0 +1,99 @@
+use crate::days;
+use crate::input::inputs_dir;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// The day's source files and every `day-N*.txt` input next to it
+pub fn watched_paths(year: u32, day: u32) -> Vec<PathBuf> {
+    let mut paths = vec![days::source_path(year, day)];
+    if let Some(solver) = days::find_solver(year, day) {
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        paths.extend(solver.extra_sources().iter().map(|source| src.join(source)));
+    }
+    paths.extend(matching_inputs(&inputs_dir(year), day));
+    paths
+}
+
+fn matching_inputs(dir: &Path, day: u32) -> Vec<PathBuf> {
+    let exact = format!("day-{}.txt", day);
+    let prefix = format!("day-{}-", day);
+    let mut paths: Vec<PathBuf> = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(
+                |path| match path.file_name().and_then(|name| name.to_str()) {
+                    Some(name) => {
+                        name == exact || (name.starts_with(&prefix) && name.ends_with(".txt"))
+                    }
+                    None => false,
+                },
+            )
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    paths.sort();
+    paths
+}
+
+/// Modification times of watched files, `None` for missing ones
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Snapshot {
+    modified: BTreeMap<PathBuf, Option<SystemTime>>,
+}
+
+impl Snapshot {
+    pub fn take(paths: &[PathBuf]) -> Snapshot {
+        let modified = paths
+            .iter()
+            .map(|path| {
+                let time = fs::metadata(path).and_then(|m| m.modified()).ok();
+                (path.clone(), time)
+            })
+            .collect();
+        Snapshot { modified }
+    }
+
+    /// Files which were added, removed or modified since `previous`
+    pub fn changed_since(&self, previous: &Snapshot) -> Vec<PathBuf> {
+        let mut changed: Vec<PathBuf> = self
+            .modified
+            .iter()
+            .filter(|(path, time)| previous.modified.get(*path) != Some(time))
+            .map(|(path, _)| path.clone())
+            .collect();
+        changed.extend(
+            previous
+                .modified
+                .keys()
+                .filter(|path| !self.modified.contains_key(*path))
+                .cloned(),
+        );
+        changed
+    }
+}
+
+/// Answers of one run, keyed by input label and part
+pub type Answers = BTreeMap<(String, u32), String>;
+
+/// One line per answer, marking answers that differ from the previous run
+pub fn render_diff(previous: Option<&Answers>, current: &Answers) -> String {
+    let mut lines = String::new();
+    for ((label, part), answer) in current.iter() {
+        let change = match previous.map(|p| p.get(&(label.clone(), *part))) {
+            None => String::new(),
+            Some(None) => " (new)".to_string(),
+            Some(Some(old)) if old == answer => " (unchanged)".to_string(),
+            Some(Some(old)) => format!(" (was {})", old),
+        };
+        lines.push_str(&format!("{} part {}: {}{}\n", label, part, answer, change));
+    }
+    if let Some(previous) = previous {
+        for (label, part) in previous.keys().filter(|key| !current.contains_key(*key)) {
+            lines.push_str(&format!("{} part {}: no answer anymore\n", label, part));
+        }
+    }
+    lines
+}
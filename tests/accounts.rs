@@ -0,0 +1,75 @@
+mod common;
+
+use aoc::accounts::{self, Cell};
+use aoc::days;
+use aoc::input::{default_input_path, example_input_path};
+use std::fs;
+
+#[test]
+fn solves_every_account() {
+    let dir = common::temp_dir("accounts");
+    for name in ["alice", "bob", "carol"].iter() {
+        fs::create_dir_all(dir.join(name).join("2021")).unwrap();
+    }
+    fs::write(dir.join("notes.txt"), "not an account").unwrap();
+    fs::copy(
+        default_input_path(2021, 6),
+        dir.join("alice/2021/day-6.txt"),
+    )
+    .unwrap();
+    fs::copy(example_input_path(2021, 6), dir.join("bob/2021/day-6.txt")).unwrap();
+    fs::copy(example_input_path(2021, 7), dir.join("bob/2021/day-7.txt")).unwrap();
+    fs::write(dir.join("carol/2021/day-7.txt"), "16,x\n").unwrap();
+    // Inputs of other years are not used
+    fs::create_dir_all(dir.join("alice/2022")).unwrap();
+    fs::copy(
+        example_input_path(2021, 7),
+        dir.join("alice/2022/day-7.txt"),
+    )
+    .unwrap();
+    // Inputs outside a year directory are the first year's, unless it has
+    // its own
+    fs::copy(example_input_path(2021, 6), dir.join("carol/day-6.txt")).unwrap();
+    fs::copy(example_input_path(2021, 7), dir.join("carol/day-7.txt")).unwrap();
+
+    let found = accounts::discover(&dir).unwrap();
+    let names: Vec<&str> = found.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["alice", "bob", "carol"]);
+
+    let solvers = [
+        days::find_solver(2021, 6).unwrap(),
+        days::find_solver(2021, 7).unwrap(),
+    ];
+    let matrix = accounts::solve_matrix(&found, 2021, &solvers, &[1], 2);
+    assert_eq!(
+        matrix.cells[&(6, 1)],
+        [
+            Cell::Answer("386640".to_string()),
+            Cell::Answer("5934".to_string()),
+            Cell::Answer("5934".to_string())
+        ]
+    );
+    assert_eq!(matrix.cells[&(7, 1)][0], Cell::Missing);
+    assert_eq!(matrix.cells[&(7, 1)][1], Cell::Answer("37".to_string()));
+    assert!(matches!(matrix.cells[&(7, 1)][2], Cell::Failed(_)));
+
+    let table = accounts::render_matrix(&matrix);
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines[0], "Day  Part     alice     bob   carol");
+    assert_eq!(lines[1], "6    1       386640    5934    5934");
+    assert!(lines[3].starts_with("Day 7 part 1 for carol: Parse error"));
+
+    let matrix = accounts::solve_matrix(&found, 2022, &solvers, &[1], 2);
+    assert_eq!(
+        matrix.cells[&(6, 1)],
+        [Cell::Missing, Cell::Missing, Cell::Missing]
+    );
+    assert_eq!(matrix.cells[&(7, 1)][0], Cell::Answer("37".to_string()));
+}
+
+#[test]
+fn needs_account_directories() {
+    let dir = common::temp_dir("accounts-empty");
+    assert!(accounts::discover(&dir).is_err());
+    assert!(accounts::discover(&dir.join("missing")).is_err());
+}
@@ -0,0 +1,24 @@
+use aoc::bench::{self, BenchConfig};
+use aoc::days;
+use aoc::input::{example_input_path, InputSource};
+
+#[test]
+fn bench_reports_every_stage() {
+    let solver = days::find_solver(2021, 6).unwrap();
+    let raw_input = InputSource::File(example_input_path(2021, 6))
+        .read()
+        .unwrap();
+    let config = BenchConfig {
+        warmup: 1,
+        iterations: 3,
+    };
+    let result = bench::bench_solver(solver, &raw_input, &config).unwrap();
+    assert_eq!(result.day, 6);
+    for timings in [result.parse, result.part1, result.part2].iter() {
+        assert!(timings.min <= timings.median);
+    }
+
+    let markdown = bench::render_markdown(2021, &[result]);
+    assert!(markdown.starts_with("| Day | Parse | Part 1 | Part 2 |"));
+    assert!(markdown.contains(&format!("[Day 6]({})", days::source_url(2021, 6))));
+}
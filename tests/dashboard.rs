@@ -0,0 +1,93 @@
+use aoc::dashboard::{Dashboard, Status, LAST_DAY};
+use aoc::days;
+use aoc::history::{self, HistoryEntry};
+use aoc::input::InputSource;
+use aoc::runner::PartResult;
+
+// A run on the day's default input
+fn history_entry(day: u32, part: u32, answer: &str) -> HistoryEntry {
+    let raw_input = InputSource::default_for_day(2021, day).read().unwrap();
+    HistoryEntry {
+        timestamp: 0,
+        commit: None,
+        year: 2021,
+        day,
+        part,
+        answer: answer.to_string(),
+        duration_ns: 1000,
+        input_hash: history::input_hash(&raw_input),
+    }
+}
+
+#[test]
+fn rows_follow_the_registry() {
+    let dashboard = Dashboard::new(2021, &[]);
+    assert_eq!(dashboard.rows.len(), LAST_DAY as usize);
+    for row in dashboard.rows.iter() {
+        let registered = days::find_solver(2021, row.day).is_some();
+        assert_eq!(
+            row.status == Status::Unimplemented,
+            !registered,
+            "Day {}",
+            row.day
+        );
+    }
+}
+
+#[test]
+fn shows_last_answers_from_history() {
+    let mut example = history_entry(7, 2, "168");
+    example.input_hash = history::input_hash("16,1,2,0,4,2,7,1,2,14");
+    let history = [
+        history_entry(7, 1, "37"),
+        history_entry(7, 1, "335330"),
+        history_entry(7, 2, "92439766"),
+        // A later run on another input, like the example
+        example,
+    ];
+    let dashboard = Dashboard::new(2021, &history);
+    let row = &dashboard.rows[6];
+    assert_eq!(row.status, Status::NotRun);
+    let answers: Vec<&str> = row.answers.iter().map(|a| a.answer.as_str()).collect();
+    assert_eq!(answers, ["335330", "92439766"]);
+}
+
+#[test]
+fn runs_update_the_selected_row() {
+    let mut dashboard = Dashboard::new(2021, &[]);
+    dashboard.select_previous();
+    dashboard.select_next();
+    assert_eq!(dashboard.selected_day(), 2);
+    dashboard.finish_run(Ok(vec![PartResult {
+        day: 2,
+        part: 1,
+        answer: "150".to_string(),
+        duration_ns: 2000,
+        input_path: String::new(),
+        memory: None,
+        parse_memory: None,
+    }]));
+    assert_eq!(dashboard.rows[1].status, Status::Solved);
+
+    let screen = dashboard.render("forward 5\ndown 5", 80, 24);
+    assert!(screen.len() <= 24);
+    assert!(screen.iter().all(|line| line.chars().count() <= 80));
+    assert!(screen.iter().any(|line| line.starts_with(">   2  solved")));
+    assert!(screen.contains(&"Part 1: 150 (2.0 µs)".to_string()));
+    assert_eq!(screen.last().unwrap(), "down 5");
+
+    dashboard.finish_run(Err("Parse error".to_string()));
+    let screen = dashboard.render("", 80, 24);
+    assert!(screen.contains(&"Error: Parse error".to_string()));
+}
+
+#[test]
+fn scrolls_to_the_selection() {
+    let mut dashboard = Dashboard::new(2021, &[]);
+    for _ in 0..LAST_DAY + 5 {
+        dashboard.select_next();
+    }
+    assert_eq!(dashboard.selected_day(), LAST_DAY);
+    let screen = dashboard.render("", 80, 20);
+    assert!(screen.iter().any(|line| line.starts_with(">  25")));
+}
@@ -0,0 +1,23 @@
+use aoc::dates::{civil_from_days, days_from_civil, days_in_month};
+
+#[test]
+fn converts_between_dates_and_days() {
+    assert_eq!(days_from_civil(1970, 1, 1), Some(0));
+    assert_eq!(days_from_civil(2021, 12, 1), Some(18_962));
+    assert_eq!(days_from_civil(1969, 12, 31), Some(-1));
+    for days in (-800_000..800_000).step_by(997) {
+        let (year, month, day) = civil_from_days(days);
+        assert_eq!(days_from_civil(year, month, day), Some(days));
+    }
+}
+
+#[test]
+fn rejects_impossible_dates() {
+    assert_eq!(days_from_civil(2021, 2, 31), None);
+    assert_eq!(days_from_civil(2021, 2, 29), None);
+    assert_eq!(days_from_civil(2021, 0, 1), None);
+    assert_eq!(days_from_civil(2021, 1, 0), None);
+    assert_eq!(days_in_month(2000, 2), Some(29));
+    assert_eq!(days_in_month(1900, 2), Some(28));
+    assert_eq!(days_in_month(2021, 13), None);
+}
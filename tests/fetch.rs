@@ -0,0 +1,104 @@
+mod common;
+
+use aoc::fetch::{self, HttpBackend, InputCache};
+use common::MockServer;
+use std::fs;
+use std::process::Command;
+
+const PUZZLE: &str = "<article><p>For example:</p>\
+<pre><code>3,4,3,1,2\n</code></pre>\
+<p>Another block:</p><pre><code>ignored</code></pre></article>";
+
+fn puzzle_server() -> MockServer {
+    MockServer::start(|request| match request.path.as_str() {
+        "/2021/day/6/input" => (200, "3,4,3,1,2,5\n".to_string()),
+        "/2021/day/6" => (200, PUZZLE.to_string()),
+        _ => (404, "Not found".to_string()),
+    })
+}
+
+#[test]
+fn fetches_input_once_and_caches_it() {
+    let server = puzzle_server();
+    let dir = common::temp_dir("fetch-input");
+    let cache = InputCache::new(dir.clone(), HttpBackend::new(&server.url, "secret"));
+
+    let path = cache.input(2021, 6).unwrap();
+    assert_eq!(path, dir.join("2021/day-6.txt"));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "3,4,3,1,2,5\n");
+    cache.input(2021, 6).unwrap();
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1, "Cached inputs must not be fetched again");
+    assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+    assert!(requests[0].header("User-Agent").is_some());
+}
+
+#[test]
+fn stores_examples_separately() {
+    let server = puzzle_server();
+    let dir = common::temp_dir("fetch-example");
+    let cache = InputCache::new(dir.clone(), HttpBackend::new(&server.url, "secret"));
+
+    let path = cache.example(2021, 6).unwrap();
+    assert_eq!(path, dir.join("2021/day-6-example.txt"));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "3,4,3,1,2\n");
+    assert!(!dir.join("2021/day-6.txt").exists());
+}
+
+#[test]
+fn reports_server_errors() {
+    let server = puzzle_server();
+    let dir = common::temp_dir("fetch-missing");
+    let cache = InputCache::new(dir.clone(), HttpBackend::new(&server.url, "secret"));
+
+    assert!(cache.input(2021, 26).is_err());
+    assert!(!dir.join("2021/day-26.txt").exists());
+}
+
+#[test]
+fn extracts_escaped_examples() {
+    let html = "<pre><code>a &lt;-&gt; <em>b</em> &amp; c</code></pre>";
+    assert_eq!(fetch::extract_example(html).unwrap(), "a <-> b & c");
+    assert_eq!(fetch::extract_example("<p>no example</p>"), None);
+}
+
+#[test]
+fn cached_inputs_need_no_session() {
+    let dir = common::temp_dir("fetch-offline");
+    fs::create_dir_all(dir.join("cache/2021")).unwrap();
+    fs::write(dir.join("cache/2021/day-1.txt"), "1\n2\n3\n").unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args([
+            "run",
+            "--day",
+            "1",
+            "--part",
+            "1",
+            "--cached",
+            "--no-history",
+        ])
+        .env("AOC_CACHE_DIR", dir.join("cache"))
+        .env("XDG_CONFIG_HOME", dir.join("config"))
+        .env_remove("AOC_SESSION")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("Day 1 part 1: 2"));
+
+    // Anything not cached yet still needs one
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--day", "2", "--cached", "--no-history"])
+        .env("AOC_CACHE_DIR", dir.join("cache"))
+        .env("XDG_CONFIG_HOME", dir.join("config"))
+        .env_remove("AOC_SESSION")
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No session token"));
+}
@@ -0,0 +1,78 @@
+mod common;
+
+use aoc::history::{self, History, HistoryEntry};
+use std::fs;
+
+fn entry(timestamp: u64, answer: &str, duration_ns: u128, input_hash: &str) -> HistoryEntry {
+    HistoryEntry {
+        timestamp,
+        commit: Some("abc123".to_string()),
+        year: 2021,
+        day: 7,
+        part: 2,
+        answer: answer.to_string(),
+        duration_ns,
+        input_hash: input_hash.to_string(),
+    }
+}
+
+#[test]
+fn hashes_inputs_with_fnv() {
+    assert_eq!(history::fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+    assert_eq!(history::fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+    assert_eq!(history::input_hash("a"), "af63dc4c8601ec8c");
+}
+
+#[test]
+fn appends_and_loads_entries() {
+    let history = History::new(common::temp_dir("history").join("history.jsonl"));
+    assert!(history.load().unwrap().is_empty());
+    history.append(&[entry(1, "168", 10, "aaaa")]).unwrap();
+    history.append(&[entry(2, "168", 20, "aaaa")]).unwrap();
+    let loaded = history.load().unwrap();
+    assert_eq!(
+        loaded,
+        [entry(1, "168", 10, "aaaa"), entry(2, "168", 20, "aaaa")]
+    );
+}
+
+#[test]
+fn reports_histories_it_can_not_read() {
+    let dir = common::temp_dir("history-unreadable");
+    let path = dir.join("history.jsonl");
+    fs::write(&path, [0xff, 0xfe, b'\n']).unwrap();
+    assert!(History::new(path).load().is_err());
+    assert!(History::new(dir).load().is_err());
+}
+
+#[test]
+fn flags_changed_answers() {
+    let entries = [
+        // 2021-12-07 05:00 UTC
+        entry(1_638_853_200, "168", 2_000_000, "aaaa"),
+        entry(1_638_853_260, "37", 1_000, "bbbb"),
+        entry(1_638_853_320, "168", 1_000_000, "aaaa"),
+        entry(1_638_853_380, "170", 1_500_000, "aaaa"),
+    ];
+    let report = history::render_history(&entries, 2021, 7);
+    let lines: Vec<&str> = report.lines().collect();
+    assert_eq!(lines[0], "2021 day 7 part 2");
+    assert!(lines[2].starts_with("2021-12-07 05:00  abc123"));
+    assert!(lines[2].ends_with("new input"));
+    assert!(lines[3].ends_with("new input"));
+    assert!(lines[4].ends_with("-50.0%"));
+    assert!(lines[5].ends_with("+50.0% ANSWER CHANGED (was 168)"));
+    assert_eq!(
+        lines[6],
+        "Runs: 4, answer changes: 1, time on the latest input: 2.00 ms -> 1.50 ms (-25.0%)"
+    );
+    assert_eq!(
+        history::render_history(&entries, 2021, 8),
+        "No runs of 2021 day 8 recorded yet\n"
+    );
+    // Runs of the same day in another year are not mixed in
+    assert_eq!(
+        history::render_history(&entries, 2022, 7),
+        "No runs of 2022 day 7 recorded yet\n"
+    );
+}
@@ -0,0 +1,128 @@
+#![cfg(unix)]
+
+use aoc::isolate::{self, ChildOutcome, Limits};
+use aoc::runner::PartResult;
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+fn shell(script: &str) -> Command {
+    let mut command = Command::new("sh");
+    command.args(["-c", script]);
+    command
+}
+
+fn limits(timeout_ms: u64) -> Limits {
+    Limits {
+        timeout: Duration::from_millis(timeout_ms),
+        memory_bytes: None,
+    }
+}
+
+#[test]
+fn captures_output_of_finished_children() {
+    let outcome = isolate::run_limited(shell("echo 42"), &limits(5000)).unwrap();
+    assert_eq!(
+        outcome,
+        ChildOutcome::Finished {
+            stdout: "42\n".to_string()
+        }
+    );
+}
+
+#[test]
+fn kills_children_after_the_timeout() {
+    let start = Instant::now();
+    let outcome =
+        isolate::run_limited(shell("echo looping >&2; exec sleep 10"), &limits(200)).unwrap();
+    assert!(start.elapsed() < Duration::from_secs(5));
+    assert_eq!(
+        outcome,
+        ChildOutcome::TimedOut {
+            after: Duration::from_millis(200),
+            stderr: "looping\n".to_string()
+        }
+    );
+    assert_eq!(outcome.to_string(), "timed out after 200ms: looping");
+}
+
+#[test]
+fn tells_crashes_from_failures() {
+    let outcome = isolate::run_limited(shell("echo bad input >&2; exit 2"), &limits(5000)).unwrap();
+    assert_eq!(outcome.to_string(), "exited with code 2: bad input");
+
+    let outcome =
+        isolate::run_limited(shell("echo boom >&2; kill -ABRT $$"), &limits(5000)).unwrap();
+    match outcome {
+        ChildOutcome::Crashed { status, stderr } => {
+            assert_eq!(status, "signal 6");
+            assert_eq!(stderr, "boom\n");
+        }
+        other => panic!("Expected a crash, got {:?}", other),
+    }
+}
+
+// Big enough for the binary to start, too small for the allocating shell
+const MEMORY_CAP: u64 = 64 * 1024 * 1024;
+
+// Holds about 100 MB in a shell variable
+const ALLOCATE: &str = "x=$(head -c 100000000 /dev/zero | tr '\\0' a); echo ${#x}";
+
+#[test]
+fn runs_small_children_under_the_memory_cap() {
+    let mut capped = limits(30_000);
+    capped.memory_bytes = Some(MEMORY_CAP);
+    let mut day1 = Command::new(env!("CARGO_BIN_EXE_aoc"));
+    day1.args([
+        "run",
+        "--day",
+        "1",
+        "--input-str",
+        "1\n2\n3",
+        "--format",
+        "json",
+        "--no-history",
+    ]);
+    let stdout = match isolate::run_limited(day1, &capped).unwrap() {
+        ChildOutcome::Finished { stdout } => stdout,
+        outcome => panic!("Expected the child to finish, got {:?}", outcome),
+    };
+    let results: Vec<PartResult> = serde_json::from_str(&stdout).unwrap();
+    let answers: Vec<&str> = results.iter().map(|r| r.answer.as_str()).collect();
+    assert_eq!(answers, ["2", "0"]);
+}
+
+#[test]
+fn isolated_runs_keep_the_output_format() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["run", "--day", "1", "--input-str", "1\n2\n3", "--isolate"])
+        .args(["--format", "json", "--no-history"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let results: Vec<PartResult> = serde_json::from_slice(&output.stdout).unwrap();
+    let answers: Vec<&str> = results.iter().map(|r| r.answer.as_str()).collect();
+    assert_eq!(answers, ["2", "0"]);
+}
+
+#[test]
+fn stops_children_allocating_past_the_memory_cap() {
+    let outcome = isolate::run_limited(shell(ALLOCATE), &limits(30_000)).unwrap();
+    assert_eq!(
+        outcome,
+        ChildOutcome::Finished {
+            stdout: "100000000\n".to_string()
+        }
+    );
+
+    let mut capped = limits(30_000);
+    capped.memory_bytes = Some(MEMORY_CAP);
+    let outcome = isolate::run_limited(shell(ALLOCATE), &capped).unwrap();
+    assert!(
+        matches!(
+            outcome,
+            ChildOutcome::Crashed { .. } | ChildOutcome::Failed { .. }
+        ),
+        "Expected the capped child to fail, got {:?}",
+        outcome
+    );
+}
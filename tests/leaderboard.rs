@@ -0,0 +1,55 @@
+use aoc::leaderboard::{self, Leaderboard};
+use std::path::Path;
+
+fn load() -> Leaderboard {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/data/leaderboard.json");
+    Leaderboard::load(&path).unwrap()
+}
+
+#[test]
+fn computes_local_scores() {
+    let leaderboard = load();
+    let standings = leaderboard::standings(&leaderboard).unwrap();
+    let names: Vec<&str> = standings.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, ["Alice", "Bob", "(anonymous user #3)"]);
+    // Scores match the ones in the export
+    for standing in standings.iter() {
+        let member = leaderboard
+            .members
+            .values()
+            .find(|m| m.display_name() == standing.name)
+            .unwrap();
+        assert_eq!(
+            standing.local_score, member.local_score,
+            "{}",
+            standing.name
+        );
+        assert_eq!(standing.stars, member.stars, "{}", standing.name);
+    }
+    assert_eq!(standings[0].deltas.get(&1), Some(&300));
+    assert_eq!(standings[0].deltas.get(&2), Some(&3000));
+    assert_eq!(standings[1].deltas.get(&1), Some(&10800));
+    assert_eq!(standings[1].deltas.get(&2), None);
+}
+
+#[test]
+fn renders_standings() {
+    let standings = leaderboard::standings(&load()).unwrap();
+    let table = leaderboard::render_table(&standings);
+    assert!(table.lines().nth(1).unwrap().starts_with("1     Alice"));
+    assert!(table.contains("5m00s"));
+
+    let markdown = leaderboard::render_markdown(&standings);
+    assert!(markdown.starts_with("| Rank | Name | Score | Stars | Day 1 | Day 2 |\n"));
+    assert!(markdown.contains("| 2 | Bob | 8 | 3 | 3h00m | - |"));
+}
+
+#[test]
+fn rejects_invalid_days() {
+    let json = r#"{"event": "2021", "owner_id": 1, "members": {"1": {"id": 1,
+        "name": "Alice", "stars": 1, "local_score": 1,
+        "completion_day_level": {"26": {"1": {"get_star_ts": 1}}}}}}"#;
+    let leaderboard = Leaderboard::from_json(json).unwrap();
+    assert!(leaderboard::standings(&leaderboard).is_err());
+    assert!(Leaderboard::from_json("{}").is_err());
+}
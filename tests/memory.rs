@@ -0,0 +1,39 @@
+use aoc::memory;
+
+#[test]
+#[cfg(not(feature = "alloc-stats"))]
+fn nothing_is_measured_by_default() {
+    let (value, stats) = memory::measure(|| vec![1u8; 100].len());
+    assert_eq!(value, 100);
+    assert_eq!(stats, None);
+}
+
+#[test]
+#[cfg(feature = "alloc-stats")]
+fn counts_stage_allocations() {
+    let (_, stats) = memory::measure(|| {
+        let mut grown: Vec<u64> = Vec::new();
+        for i in 0..1000 {
+            grown.push(i);
+        }
+        let fixed = vec![0u8; 64 * 1024];
+        fixed.len() + grown.len()
+    });
+    let stats = stats.unwrap();
+    assert!(stats.peak_bytes >= 64 * 1024 + 8000, "{:?}", stats);
+    assert!(stats.allocations > 2, "{:?}", stats);
+
+    // Live memory from before the stage is not counted against it
+    let kept = vec![0u8; 1024 * 1024];
+    let (_, stats) = memory::measure(|| Vec::<u8>::with_capacity(10).capacity());
+    assert_eq!(stats.unwrap().allocations, 1);
+    assert!(stats.unwrap().peak_bytes < 1024);
+    drop(kept);
+}
+
+#[test]
+fn formats_bytes() {
+    assert_eq!(memory::format_bytes(512), "512 B");
+    assert_eq!(memory::format_bytes(2048), "2.0 KiB");
+    assert_eq!(memory::format_bytes(3 * 1024 * 1024), "3.0 MiB");
+}
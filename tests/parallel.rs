@@ -0,0 +1,78 @@
+use aoc::days;
+use aoc::error::{AocError, Result};
+use aoc::input::{example_input_path, InputSource};
+use aoc::report::{self, OutputFormat};
+use aoc::runner::{self, Job};
+use aoc::solution::Solution;
+
+// Part 1 panics, part 2 fails like a regular solver error
+struct Broken;
+
+impl Solution for Broken {
+    const DAY: u32 = 99;
+    type Input = String;
+    type Answer = u32;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.to_string())
+    }
+
+    fn part1(_: &String) -> Result<u32> {
+        panic!("Index out of bounds")
+    }
+
+    fn part2(_: &String) -> Result<u32> {
+        Err(AocError::solver("No answer"))
+    }
+}
+
+#[test]
+fn failures_do_not_stop_other_days() {
+    let broken = Broken;
+    let mut jobs = Vec::new();
+    for part in [2, 1].iter() {
+        jobs.push(Job {
+            solver: &broken,
+            source: InputSource::Inline(String::new()),
+            part: *part,
+        });
+        jobs.push(Job {
+            solver: days::find_solver(2021, 6).unwrap(),
+            source: InputSource::File(example_input_path(2021, 6)),
+            part: *part,
+        });
+    }
+    let reports = runner::solve_parallel(&jobs, 3);
+
+    let order: Vec<(u32, u32)> = reports.iter().map(|r| (r.day, r.part)).collect();
+    assert_eq!(order, [(6, 1), (6, 2), (99, 1), (99, 2)]);
+    assert_eq!(reports[0].outcome.as_ref().unwrap().answer, "5934");
+    assert_eq!(reports[1].outcome.as_ref().unwrap().answer, "26984457539");
+    assert_eq!(
+        reports[2].outcome,
+        Err("Panicked: Index out of bounds".to_string())
+    );
+    assert_eq!(
+        reports[3].outcome,
+        Err("Solver failed: No answer".to_string())
+    );
+
+    let summary = report::render_summary(&reports);
+    assert_eq!(summary.lines().count(), 5);
+    assert!(summary
+        .lines()
+        .nth(3)
+        .unwrap()
+        .ends_with("failed: Panicked: Index out of bounds"));
+    assert_eq!(
+        report::render_reports(&reports, OutputFormat::Plain),
+        summary
+    );
+
+    // Machine readable output leaves the failed parts out
+    let csv = report::render_reports(&reports, OutputFormat::Csv);
+    let rows: Vec<&str> = csv.lines().skip(1).collect();
+    assert_eq!(rows.len(), 2);
+    assert!(rows[0].starts_with("6,1,5934,"));
+    assert!(rows[1].starts_with("6,2,26984457539,"));
+}
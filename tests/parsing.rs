@@ -0,0 +1,105 @@
+use aoc::days;
+
+// Parses `input` with the solver of 2021 `day` and solves `part`, keeping
+// only the error message
+fn solve(day: u32, input: &str, part: u32) -> Result<String, String> {
+    let solver = days::find_solver(2021, day).unwrap();
+    let input = solver.parse_input(input).map_err(|e| e.to_string())?;
+    solver
+        .solve_part(input.as_ref(), part)
+        .map_err(|e| e.to_string())
+}
+
+const DAY8_PATTERNS: &str = "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab";
+
+#[test]
+fn day8_rejects_empty_or_long_outputs() {
+    let empty = format!("{} |", DAY8_PATTERNS);
+    assert_eq!(
+        solve(8, &empty, 2).unwrap_err(),
+        "Parse error at line 1, column 61: Expected 1 to 9 output digits (found \"\")"
+    );
+    let long = format!("{} | {}", DAY8_PATTERNS, ["ab"; 10].join(" "));
+    assert!(solve(8, &long, 2)
+        .unwrap_err()
+        .contains("Expected 1 to 9 output digits"));
+    let nine = format!("{} | {}", DAY8_PATTERNS, ["ab"; 9].join(" "));
+    assert_eq!(solve(8, &nine, 2).unwrap(), "111111111");
+}
+
+#[test]
+fn day8_reports_sums_past_an_i32() {
+    let fives = format!("{} | {}\n", DAY8_PATTERNS, ["cdfbe"; 9].join(" "));
+    assert_eq!(solve(8, &fives.repeat(3), 2).unwrap(), "1666666665");
+    assert_eq!(
+        solve(8, &fives.repeat(4), 2).unwrap_err(),
+        "Solver failed: Sum of the output values does not fit an i32"
+    );
+}
+
+#[test]
+fn day7_rejects_negative_positions() {
+    assert_eq!(
+        solve(7, "1,-5,-3", 1).unwrap_err(),
+        "Parse error at line 1, column 3: Crab positions can not be negative (found \"-5\")"
+    );
+}
+
+#[test]
+fn day7_handles_far_apart_crabs() {
+    assert_eq!(solve(7, "0,100000", 1).unwrap(), "100000");
+    assert_eq!(solve(7, "0,100000", 2).unwrap(), "2500050000");
+    let far = format!("0,{}", i32::MAX);
+    assert_eq!(solve(7, &far, 1).unwrap(), i32::MAX.to_string());
+    assert_eq!(solve(7, &far, 2).unwrap(), "1152921504606846976");
+    // Nine crabs on either end need about 4.5 * i32::MAX² fuel
+    let farther = format!(
+        "{}{}",
+        "0,".repeat(9),
+        vec![i32::MAX.to_string(); 9].join(",")
+    );
+    assert_eq!(
+        solve(7, &farther, 2).unwrap_err(),
+        "Solver failed: Aligning the crabs takes more fuel than fits an i64"
+    );
+}
+
+#[test]
+fn day2_reports_dives_past_an_i32() {
+    let overflow = "Solver failed: The submarine dives past what an i32 can hold";
+    assert_eq!(
+        solve(2, "forward 2000000000\ndown 2", 1).unwrap_err(),
+        overflow
+    );
+    assert_eq!(
+        solve(2, "forward 2000000000\nforward 2000000000", 1).unwrap_err(),
+        overflow
+    );
+    assert_eq!(
+        solve(2, "down 2\nforward 2000000000", 2).unwrap_err(),
+        overflow
+    );
+    assert_eq!(
+        solve(2, "forward 1000000000\ndown 2", 1).unwrap(),
+        "2000000000"
+    );
+}
+
+#[test]
+fn day3_limits_the_bit_width() {
+    let widest = format!("{}\n{}\n", "1".repeat(31), "0".repeat(31));
+    assert_eq!(solve(3, &widest, 1).unwrap(), "0");
+    assert_eq!(solve(3, &widest, 2).unwrap(), "0");
+    let ones = format!("{0}\n{0}\n{1}\n", "1".repeat(31), "0".repeat(31));
+    assert_eq!(solve(3, &ones, 1).unwrap(), "0");
+    let half = format!("{0}{1}\n{0}{1}\n{1}{0}\n", "1".repeat(16), "0".repeat(15));
+    // 0b1..10..0 * 0b0..01..1, which overflows an i32
+    assert_eq!(solve(3, &half, 1).unwrap(), "70365522984960");
+
+    for bits in [32, 64, 100].iter() {
+        let input = format!("{}\n", "1".repeat(*bits));
+        assert!(solve(3, &input, 1)
+            .unwrap_err()
+            .starts_with("Parse error at line 1, column 32: Diagnostics can have at most 31 bits"));
+    }
+}
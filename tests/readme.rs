@@ -0,0 +1,52 @@
+use aoc::readme;
+
+const README: &str = "## Advent of code
+
+<!-- days:start -->
+### 2021
+
+ [Day 1](https://example.com/day1.rs)
+
+ [Day 2](https://example.com/day2.rs) - Lots of generics
+
+ [Day 3](https://example.com/day3.rs)
+<!-- days:end -->
+
+Footer
+";
+
+#[test]
+fn regenerates_days_and_keeps_notes() {
+    let updated = readme::update_days(README, &[(2021, 2), (2022, 1)]).unwrap();
+    let expected = "## Advent of code
+
+<!-- days:start -->
+### 2021
+
+ [Day 2](https://github.com/Hyde46/advent_of_code_2021/blob/main/src/days/y2021/day2.rs) - Lots of generics
+
+### 2022
+
+ [Day 1](https://github.com/Hyde46/advent_of_code_2021/blob/main/src/days/y2022/day1.rs)
+<!-- days:end -->
+
+Footer
+";
+    assert_eq!(updated, expected);
+    // Regenerating is stable
+    assert_eq!(
+        readme::update_days(&updated, &[(2021, 2), (2022, 1)]).unwrap(),
+        updated
+    );
+}
+
+#[test]
+fn adds_days_in_order() {
+    let updated = readme::add_day(README, 2021, 4).unwrap();
+    let day3 = updated.find("[Day 3]").unwrap();
+    let day4 = updated.find("[Day 4]").unwrap();
+    assert!(day3 < day4 && day4 < updated.find("<!-- days:end -->").unwrap());
+    assert!(updated.contains("day2.rs) - Lots of generics"));
+
+    assert!(readme::add_day("No markers here\n", 2021, 4).is_err());
+}
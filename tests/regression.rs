@@ -0,0 +1,67 @@
+use aoc::days;
+use aoc::input::{default_input_path, example_input_path, InputSource};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const ANSWERS_FILE: &str = "answers.toml";
+
+fn solve(year: u32, day: u32, path: PathBuf) -> (String, String) {
+    let solver = days::find_solver(year, day).expect("Day is not registered");
+    let raw_input = InputSource::File(path).read().unwrap();
+    let input = solver.parse_input(&raw_input).unwrap();
+    (
+        solver.solve_part(input.as_ref(), 1).unwrap(),
+        solver.solve_part(input.as_ref(), 2).unwrap(),
+    )
+}
+
+macro_rules! example_test {
+    ($(#[$attr:meta])* $name:ident, $year:expr, $day:expr, $part1:expr, $part2:expr) => {
+        #[test]
+        $(#[$attr])*
+        fn $name() {
+            let (part1, part2) = solve($year, $day, example_input_path($year, $day));
+            assert_eq!(part1, $part1, "{} day {} part 1", $year, $day);
+            assert_eq!(part2, $part2, "{} day {} part 2", $year, $day);
+        }
+    };
+}
+
+// Answers for the examples given in the puzzle texts
+example_test!(y2021_day1_example, 2021, 1, "7", "5");
+example_test!(y2021_day2_example, 2021, 2, "150", "900");
+example_test!(y2021_day3_example, 2021, 3, "198", "230");
+example_test!(y2021_day4_example, 2021, 4, "4512", "1924");
+example_test!(y2021_day5_example, 2021, 5, "5", "12");
+example_test!(y2021_day6_example, 2021, 6, "5934", "26984457539");
+example_test!(y2021_day7_example, 2021, 7, "37", "168");
+example_test!(y2021_day8_example, 2021, 8, "26", "61229");
+
+#[test]
+fn real_answers() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(ANSWERS_FILE);
+    let answers = match fs::read_to_string(&path) {
+        Ok(content) => content.parse::<toml::Value>().unwrap(),
+        // The answers file is optional
+        Err(_) => return,
+    };
+    for (year, solvers) in days::YEARS.iter() {
+        for solver in solvers.iter() {
+            let day = solver.day();
+            let expected = answers
+                .get(year.to_string())
+                .and_then(|days| days.get(format!("day-{}", day)));
+            let expected = match expected {
+                Some(expected) => expected,
+                None => continue,
+            };
+            let (part1, part2) = solve(*year, day, default_input_path(*year, day));
+            for (part, answer) in [("part1", part1), ("part2", part2)].iter() {
+                if let Some(expected) = expected.get(part) {
+                    let expected = expected.as_str().expect("Answers must be strings");
+                    assert_eq!(answer, expected, "{} day {} {}", year, day, part);
+                }
+            }
+        }
+    }
+}
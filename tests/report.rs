@@ -0,0 +1,74 @@
+use aoc::memory::AllocStats;
+use aoc::report::{self, OutputFormat};
+use aoc::runner::PartResult;
+
+fn result(answer: &str, input_path: &str) -> PartResult {
+    PartResult {
+        day: 5,
+        part: 2,
+        answer: answer.to_string(),
+        duration_ns: 1200,
+        input_path: input_path.to_string(),
+        memory: None,
+        parse_memory: None,
+    }
+}
+
+#[test]
+fn json_contains_every_field() {
+    let results = vec![result("12", "src/inputs/day-5.txt")];
+    let json = report::render(&results, OutputFormat::Json);
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        parsed,
+        serde_json::json!([{
+            "day": 5,
+            "part": 2,
+            "answer": "12",
+            "duration_ns": 1200,
+            "input_path": "src/inputs/day-5.txt"
+        }])
+    );
+}
+
+#[test]
+fn csv_quotes_fields_with_separators() {
+    let results = vec![result("1,2", "my \"inputs\"/day-5.txt")];
+    assert_eq!(
+        report::render(&results, OutputFormat::Csv),
+        "day,part,answer,duration_ns,input_path\n5,2,\"1,2\",1200,\"my \"\"inputs\"\"/day-5.txt\"\n"
+    );
+}
+
+#[test]
+fn plain_matches_runner_output() {
+    let results = vec![result("12", "src/inputs/day-5.txt")];
+    assert_eq!(
+        report::render(&results, OutputFormat::Plain),
+        "Day 5 part 2: 12\n"
+    );
+    assert!("xml".parse::<OutputFormat>().is_err());
+}
+
+#[test]
+fn reports_memory_per_stage() {
+    let mut measured = result("12", "day-5.txt");
+    measured.parse_memory = Some(AllocStats {
+        peak_bytes: 4096,
+        allocations: 3,
+    });
+    measured.memory = Some(AllocStats {
+        peak_bytes: 512,
+        allocations: 1,
+    });
+    let results = vec![measured];
+    assert_eq!(
+        report::render(&results, OutputFormat::Plain),
+        "Day 5 part 2: 12 (parse peak 4.0 KiB in 3 allocations, part peak 512 B in 1 allocations)\n"
+    );
+    assert_eq!(
+        report::render(&results, OutputFormat::Csv),
+        "day,part,answer,duration_ns,input_path,parse_peak_bytes,parse_allocations,peak_bytes,allocations\n\
+         5,2,12,1200,day-5.txt,4096,3,512,1\n"
+    );
+}
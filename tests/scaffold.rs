@@ -0,0 +1,167 @@
+use aoc::scaffold;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+// Minimal copy of the files the scaffolding edits
+fn project(name: &str) -> PathBuf {
+    let root = std::env::temp_dir().join(format!("aoc-scaffold-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&root);
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for file in [
+        "src/days/mod.rs",
+        "src/days/y2021/mod.rs",
+        "tests/regression.rs",
+        "Readme.md",
+    ]
+    .iter()
+    {
+        let target = root.join(file);
+        fs::create_dir_all(target.parent().unwrap()).unwrap();
+        fs::copy(manifest.join(file), target).unwrap();
+    }
+    fs::create_dir_all(root.join("src/inputs/2021")).unwrap();
+    root
+}
+
+// Generated Rust sources must not need a `cargo fmt` afterwards. Sources
+// go through stdin, so rustfmt does not look for the modules they declare.
+// Skipped where rustfmt is not installed.
+fn assert_rustfmt_clean(touched: &[PathBuf]) {
+    let sources = touched
+        .iter()
+        .filter(|path| path.extension().is_some_and(|e| e == "rs"))
+        .filter(|path| !path.ends_with("tests/regression.rs"));
+    for source in sources {
+        let rustfmt = Command::new("rustfmt")
+            .args(["--edition", "2018", "--emit", "stdout"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn();
+        let mut rustfmt = match rustfmt {
+            Ok(rustfmt) => rustfmt,
+            Err(_) => return,
+        };
+        let content = fs::read_to_string(source).unwrap();
+        let mut stdin = rustfmt.stdin.take().unwrap();
+        stdin.write_all(content.as_bytes()).unwrap();
+        drop(stdin);
+        let output = rustfmt.wait_with_output().unwrap();
+        assert!(output.status.success());
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            content,
+            "{} is not rustfmt clean",
+            source.display()
+        );
+    }
+}
+
+#[test]
+fn scaffolds_and_registers_a_new_day() {
+    let root = project("new");
+    fs::write(
+        root.join("src/inputs/2021/day-12.txt"),
+        "already downloaded",
+    )
+    .unwrap();
+
+    let touched = scaffold::scaffold_day(&root, 2021, 12).unwrap();
+    assert!(touched.contains(&root.join("src/days/y2021/day12.rs")));
+    assert!(touched.contains(&root.join("src/inputs/2021/day-12-example.txt")));
+    assert!(!touched.contains(&root.join("src/days/mod.rs")));
+
+    let module = fs::read_to_string(root.join("src/days/y2021/day12.rs")).unwrap();
+    assert!(module.contains("impl Solution for Day12"));
+    let registry = fs::read_to_string(root.join("src/days/y2021/mod.rs")).unwrap();
+    assert!(registry.contains("pub mod day1;\npub mod day12;\npub mod day2;\n"));
+    assert!(registry.contains("    &day12::Day12,\n];"));
+    let tests = fs::read_to_string(root.join("tests/regression.rs")).unwrap();
+    assert!(tests.contains("example_test!(#[ignore] y2021_day12_example, 2021, 12, \"?\", \"?\");"));
+    let readme = fs::read_to_string(root.join("Readme.md")).unwrap();
+    assert!(readme.contains(
+        " [Day 12](https://github.com/Hyde46/advent_of_code_2021/blob/main/src/days/y2021/day12.rs)"
+    ));
+    // Existing inputs are left alone
+    let input = fs::read_to_string(root.join("src/inputs/2021/day-12.txt")).unwrap();
+    assert_eq!(input, "already downloaded");
+
+    assert_rustfmt_clean(&touched);
+
+    assert!(scaffold::scaffold_day(&root, 2021, 12).is_err());
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn only_scaffolds_puzzle_days() {
+    let root = project("range");
+    for day in [0, 26].iter() {
+        let error = scaffold::scaffold_day(&root, 2021, *day).unwrap_err();
+        assert!(error.to_string().contains("expected 1 to 25"));
+    }
+    assert!(!root.join("src/days/y2021/day26.rs").exists());
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn leaves_the_project_alone_when_a_file_can_not_be_updated() {
+    let root = project("markers");
+    let tests_path = root.join("tests/regression.rs");
+    let tests = fs::read_to_string(&tests_path).unwrap();
+    fs::write(&tests_path, "// No example tests yet\n").unwrap();
+    let registry = fs::read_to_string(root.join("src/days/y2021/mod.rs")).unwrap();
+
+    assert!(scaffold::scaffold_day(&root, 2021, 9).is_err());
+    assert!(!root.join("src/days/y2021/day9.rs").exists());
+    assert!(!root.join("src/inputs/2021/day-9.txt").exists());
+    let unchanged = fs::read_to_string(root.join("src/days/y2021/mod.rs")).unwrap();
+    assert_eq!(unchanged, registry);
+
+    // Once the marker is back, a retry goes through
+    fs::write(&tests_path, tests).unwrap();
+    scaffold::scaffold_day(&root, 2021, 9).unwrap();
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn first_day_of_a_year_registers_the_year() {
+    let root = project("year");
+
+    let touched = scaffold::scaffold_day(&root, 2022, 1).unwrap();
+    assert!(touched.contains(&root.join("src/days/mod.rs")));
+    assert!(touched.contains(&root.join("src/days/y2022/mod.rs")));
+    assert!(touched.contains(&root.join("src/inputs/2022/day-1.txt")));
+
+    let years = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+    assert!(years.contains("pub mod y2021;\npub mod y2022;\n"));
+    assert!(years.contains(
+        "pub static YEARS: &[(u32, &[&dyn Solver])] = \
+         &[(2021, y2021::SOLVERS), (2022, y2022::SOLVERS)];\n"
+    ));
+    let registry = fs::read_to_string(root.join("src/days/y2022/mod.rs")).unwrap();
+    assert!(registry.contains("pub mod day1;\n"));
+    assert!(registry.contains("pub static SOLVERS: &[&dyn Solver] = &[&day1::Day1];\n"));
+    assert_rustfmt_clean(&touched);
+
+    let readme = fs::read_to_string(root.join("Readme.md")).unwrap();
+    let y2021 = readme.find("### 2021").unwrap();
+    let y2022 = readme.find("### 2022").unwrap();
+    assert!(y2021 < y2022);
+    assert!(readme[y2022..].contains("/src/days/y2022/day1.rs)"));
+    // The notes of earlier days survive the rewrite
+    assert!(readme.contains("day2.rs) - Lot's of generics and trait bound action"));
+
+    // Growing arrays are wrapped like rustfmt wraps them
+    let touched = scaffold::scaffold_day(&root, 2023, 1).unwrap();
+    let years = fs::read_to_string(root.join("src/days/mod.rs")).unwrap();
+    assert!(years.contains(
+        "= &[\n    (2021, y2021::SOLVERS),\n    (2022, y2022::SOLVERS),\n    (2023, y2023::SOLVERS),\n];\n"
+    ));
+    assert_rustfmt_clean(&touched);
+    let touched = scaffold::scaffold_day(&root, 2022, 2).unwrap();
+    let registry = fs::read_to_string(root.join("src/days/y2022/mod.rs")).unwrap();
+    assert!(registry.contains("= &[&day1::Day1, &day2::Day2];\n"));
+    assert_rustfmt_clean(&touched);
+    fs::remove_dir_all(&root).unwrap();
+}
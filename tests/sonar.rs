@@ -0,0 +1,202 @@
+use aoc::days::y2021::day1::Day1;
+use aoc::solution::Solution;
+use aoc::sonar::{
+    count_window_increases, depth_stats, parse_log, parse_timestamp, render_sparkline,
+    render_stats, render_svg, SensorAnalyzers, SensorLog, SonarAnalyzer, SonarFormat,
+};
+
+const EXAMPLE: [u32; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+#[test]
+fn counts_increases_for_any_width() {
+    assert_eq!(count_window_increases(&EXAMPLE, 1), 7);
+    assert_eq!(count_window_increases(&EXAMPLE, 3), 5);
+    assert_eq!(count_window_increases(&EXAMPLE, 9), 1);
+}
+
+#[test]
+fn windows_wider_than_the_input_never_increase() {
+    assert_eq!(count_window_increases(&EXAMPLE, 10), 0);
+    assert_eq!(count_window_increases(&EXAMPLE, 11), 0);
+    assert_eq!(count_window_increases(&[], 1), 0);
+}
+
+#[test]
+fn streams_readings_through_a_window() {
+    let log = EXAMPLE
+        .iter()
+        .map(|reading| format!("{}\n", reading))
+        .collect::<String>();
+    let mut analyzer = SonarAnalyzer::new(3);
+    analyzer.read(log.as_bytes(), "example").unwrap();
+    let summary = analyzer.summary();
+    assert_eq!(summary.readings, 10);
+    assert_eq!(summary.increases, 5);
+    assert_eq!((summary.min, summary.max), (Some(199), Some(269)));
+    assert_eq!(summary.mean, Some(225.6));
+
+    // More readings can be fed after a summary was taken
+    analyzer.push(300);
+    assert_eq!(analyzer.summary().increases, 6);
+    for width in 0..12 {
+        let mut analyzer = SonarAnalyzer::new(width);
+        EXAMPLE.iter().for_each(|reading| analyzer.push(*reading));
+        let expected = count_window_increases(&EXAMPLE, width) as u64;
+        assert_eq!(analyzer.summary().increases, expected, "width {}", width);
+    }
+}
+
+#[test]
+fn streams_windows_wider_than_the_log() {
+    let mut analyzer = SonarAnalyzer::new(usize::MAX);
+    analyzer.read("1\n2\n3\n".as_bytes(), "log").unwrap();
+    assert_eq!(analyzer.summary().readings, 3);
+    assert_eq!(analyzer.summary().increases, 0);
+}
+
+#[test]
+fn reports_bad_lines_of_a_stream() {
+    let mut analyzer = SonarAnalyzer::new(1);
+    let error = analyzer
+        .read("1\n\n2\r\n 3x\n".as_bytes(), "log")
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Parse error at line 4, column 2: invalid digit found in string (found \"3x\")"
+    );
+    assert_eq!(analyzer.summary().readings, 2);
+    assert_eq!(SonarAnalyzer::new(3).summary().mean, None);
+}
+
+#[test]
+fn describes_the_depth_profile() {
+    let stats = depth_stats(&[5, 5, 5, 3, 4, 6, 6, 9, 2]).unwrap();
+    assert_eq!(stats.readings.count, 9);
+    assert_eq!((stats.readings.min, stats.readings.max), (2, 9));
+    assert_eq!(stats.readings.mean, 5.0);
+    assert_eq!(stats.readings.median, 5.0);
+    let quantiles: Vec<f64> = stats
+        .readings
+        .quantiles
+        .iter()
+        .map(|q| (q * 100.0).round() / 100.0)
+        .collect();
+    assert_eq!(quantiles, [2.8, 4.0, 6.0, 6.6]);
+    let differences = stats.differences.unwrap();
+    assert_eq!(differences.count, 8);
+    assert_eq!((differences.min, differences.max), (-7, 3));
+    assert_eq!(differences.median, 0.0);
+    assert_eq!(stats.longest_increase, 3);
+    assert_eq!(stats.longest_decrease, 2);
+    // 5, 5, 5 and 6, 6
+    assert_eq!(stats.plateaus, 2);
+
+    let single = depth_stats(&[7]).unwrap();
+    assert_eq!(single.readings.variance, 0.0);
+    assert_eq!(single.differences, None);
+    assert_eq!(single.longest_increase, 1);
+    assert_eq!(depth_stats(&[]), None);
+}
+
+#[test]
+fn renders_stats_per_series() {
+    let report = render_stats(&depth_stats(&EXAMPLE).unwrap());
+    let lines: Vec<&str> = report.lines().collect();
+    assert_eq!(lines[0], "               readings  differences");
+    assert_eq!(lines[1], "count                10            9");
+    assert_eq!(lines[7], "median           209.00         3.00");
+    assert_eq!(lines[8], "p10              199.90        -9.20");
+    assert_eq!(lines[12], "Longest increasing run: 4 readings");
+    assert_eq!(lines[14], "Plateaus: 0");
+}
+
+#[test]
+fn marks_window_increases_in_the_svg() {
+    let svg = render_svg(&EXAMPLE, 3);
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.ends_with("</svg>\n"));
+    assert_eq!(svg.matches("<circle").count(), 5);
+    // The shallowest reading sits at the top of the chart, the deepest at
+    // the bottom
+    assert!(svg.contains("points=\"40.0,40.0 "));
+    assert!(svg.contains(" 760.0,241.1\""));
+    assert_eq!(render_svg(&EXAMPLE, 1).matches("<circle").count(), 7);
+    assert_eq!(render_svg(&[], 3).matches("<circle").count(), 0);
+}
+
+#[test]
+fn averages_readings_into_sparkline_columns() {
+    assert_eq!(render_sparkline(&EXAMPLE, 80), "▁▁▂▂▁▂▅█▇▇\n");
+    assert_eq!(render_sparkline(&EXAMPLE, 2), "▁█\n");
+    assert_eq!(render_sparkline(&[3, 3, 3], 3), "▁▁▁\n");
+    assert_eq!(render_sparkline(&[], 3), "\n");
+}
+
+const CSV: &str = "timestamp,depth,sensor_id
+1638316800,199,b
+1638316800,200,a
+1638316801,201,a
+2021-12-01T00:00:02Z,208,a
+1638316802,198,b
+";
+
+#[test]
+fn groups_csv_readings_by_sensor() {
+    let logs = parse_log(CSV, None).unwrap();
+    assert_eq!(
+        logs,
+        [
+            SensorLog {
+                sensor: Some("a".to_string()),
+                readings: vec![200, 201, 208],
+            },
+            SensorLog {
+                sensor: Some("b".to_string()),
+                readings: vec![199, 198],
+            },
+        ]
+    );
+    assert_eq!(Day1::part1(&logs).unwrap(), "a: 2, b: 0");
+    // Bare depths and CSV rows without a sensor keep a bare answer
+    let bare = parse_log("3\n4\n", None).unwrap();
+    assert_eq!(Day1::part1(&bare).unwrap(), "1");
+    let unnamed = parse_log("1,3\n2,4\n", None).unwrap();
+    assert_eq!(unnamed, bare);
+    assert!(parse_log("3\n4\n", Some(SonarFormat::Csv)).is_err());
+}
+
+#[test]
+fn rejects_timestamps_going_back() {
+    let error = parse_log("5,1,a\n6,1,b\n5,2,b\n", None).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Parse error at line 3, column 1: Timestamp is not after the previous reading of sensor b (found \"5\")"
+    );
+    let error = parse_log("5,1\nyesterday,2\n", None).unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("Parse error at line 2, column 1"));
+    let error = parse_log("5,1,a,extra\n", None).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Expected timestamp,depth[,sensor_id]"));
+    assert_eq!(parse_timestamp("1970-01-02T00:00:01Z"), Some(86_401));
+    assert_eq!(parse_timestamp("2021-12-01 00:00:00"), Some(1_638_316_800));
+    assert_eq!(parse_timestamp("2021-13-01T00:00:00"), None);
+    // Days are checked against the length of their month
+    assert_eq!(parse_timestamp("2021-02-31T00:00:00"), None);
+    assert_eq!(parse_timestamp("2021-02-29T00:00:00"), None);
+    assert_eq!(parse_timestamp("2020-02-29T00:00:00"), Some(1_582_934_400));
+    assert_eq!(parse_timestamp("2021-04-31T00:00:00"), None);
+}
+
+#[test]
+fn streams_csv_per_sensor() {
+    let mut analyzers = SensorAnalyzers::new(1, None);
+    analyzers.read(CSV.as_bytes(), "export.csv").unwrap();
+    let summaries = analyzers.summaries();
+    assert_eq!(summaries.len(), 2);
+    assert_eq!(summaries[0].0.as_deref(), Some("a"));
+    assert_eq!(summaries[0].1.increases, 2);
+    assert_eq!(summaries[1].1.readings, 2);
+}
@@ -0,0 +1,93 @@
+mod common;
+
+use aoc::fetch::HttpBackend;
+use aoc::submit::{parse_response, SubmissionLog, Submitter, Verdict};
+use common::MockServer;
+use std::fs;
+
+const RIGHT: &str =
+    "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. \
+Please wait one minute before trying again.</p></article>";
+const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait \
+after submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>";
+
+// Answers 100 for day 7 part 2, everything else is too high
+fn answer_server() -> MockServer {
+    MockServer::start(|request| {
+        if request.method != "POST" || request.path != "/2021/day/7/answer" {
+            return (404, "Not found".to_string());
+        }
+        match request.body.as_str() {
+            "level=2&answer=100" => (200, RIGHT.to_string()),
+            _ => (200, TOO_HIGH.to_string()),
+        }
+    })
+}
+
+fn submitter(server: &MockServer, name: &str) -> Submitter<HttpBackend> {
+    let log = SubmissionLog::open(common::temp_dir(name).join("submissions.json")).unwrap();
+    Submitter::new(HttpBackend::new(&server.url, "secret"), log)
+}
+
+#[test]
+fn parses_verdicts() {
+    assert_eq!(parse_response(RIGHT), (Verdict::Correct, None));
+    assert_eq!(parse_response(TOO_HIGH), (Verdict::TooHigh, Some(60)));
+    assert_eq!(parse_response(TOO_RECENT), (Verdict::RateLimited, Some(65)));
+    let (verdict, _) = parse_response("<p>Something <em>new</em></p>");
+    assert_eq!(verdict, Verdict::Unknown("Something new".to_string()));
+}
+
+#[test]
+fn refuses_known_wrong_answers() {
+    let server = answer_server();
+    let mut submitter = submitter(&server, "submit-wrong");
+
+    assert_eq!(
+        submitter.submit_at(2021, 7, 2, "200", 0).unwrap(),
+        Verdict::TooHigh
+    );
+    // Still within the minute the server asked to wait
+    assert!(submitter.submit_at(2021, 7, 2, "100", 30).is_err());
+    // Known to be too high, without asking the server
+    assert!(submitter.submit_at(2021, 7, 2, "200", 100).is_err());
+    assert!(submitter.submit_at(2021, 7, 2, "300", 100).is_err());
+    assert_eq!(
+        submitter.submit_at(2021, 7, 2, "100", 100).unwrap(),
+        Verdict::Correct
+    );
+    assert!(submitter.submit_at(2021, 7, 2, "99", 200).is_err());
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 2);
+    assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+}
+
+#[test]
+fn keeps_outcomes_between_runs() {
+    let server = answer_server();
+    let dir = common::temp_dir("submit-log");
+    let log = SubmissionLog::open(dir.join("submissions.json")).unwrap();
+    let mut first = Submitter::new(HttpBackend::new(&server.url, "secret"), log);
+    first.submit_at(2021, 7, 2, "200", 0).unwrap();
+
+    let log = SubmissionLog::open(dir.join("submissions.json")).unwrap();
+    assert_eq!(log.submissions().len(), 1);
+    assert!(log.check(2021, 7, 2, "200", 1000).is_some());
+    assert!(log.check(2022, 7, 2, "200", 1000).is_none());
+    assert!(log.check(2021, 7, 1, "200", 1000).is_none());
+}
+
+#[test]
+fn refuses_logs_it_can_not_read() {
+    let dir = common::temp_dir("submit-unreadable");
+    let path = dir.join("submissions.json");
+    fs::write(&path, [0xff, 0xfe, b'[']).unwrap();
+    assert!(SubmissionLog::open(path.clone()).is_err());
+    assert_eq!(fs::read(&path).unwrap(), [0xff, 0xfe, b'[']);
+
+    assert!(SubmissionLog::open(dir.clone()).is_err());
+    let missing = SubmissionLog::open(dir.join("missing.json")).unwrap();
+    assert!(missing.submissions().is_empty());
+}
@@ -0,0 +1,66 @@
+mod common;
+
+use aoc::days;
+use aoc::watch::{self, Answers, Snapshot};
+use std::fs;
+
+fn answers(entries: &[(&str, u32, &str)]) -> Answers {
+    entries
+        .iter()
+        .map(|(label, part, answer)| ((label.to_string(), *part), answer.to_string()))
+        .collect()
+}
+
+#[test]
+fn watches_source_and_inputs() {
+    let paths = watch::watched_paths(2021, 8);
+    assert_eq!(paths[0], days::source_path(2021, 8));
+    let names: Vec<String> = paths[1..]
+        .iter()
+        .map(|p| p.file_name().unwrap().to_string_lossy().to_string())
+        .collect();
+    assert_eq!(names, ["day-8-example.txt", "day-8.txt"]);
+
+    // Day 1 is mostly solved by the sonar module
+    let paths = watch::watched_paths(2021, 1);
+    assert_eq!(paths[0], days::source_path(2021, 1));
+    assert!(paths[1].ends_with("src/sonar.rs"));
+    assert!(paths[1].exists());
+}
+
+#[test]
+fn detects_changed_files() {
+    let dir = common::temp_dir("watch");
+    let kept = dir.join("kept.txt");
+    let edited = dir.join("edited.txt");
+    let created = dir.join("created.txt");
+    fs::write(&kept, "a").unwrap();
+    fs::write(&edited, "a").unwrap();
+    let paths = vec![kept.clone(), edited.clone(), created.clone()];
+    let before = Snapshot::take(&paths);
+    assert!(Snapshot::take(&paths).changed_since(&before).is_empty());
+
+    let file = fs::OpenOptions::new().write(true).open(&edited).unwrap();
+    file.set_modified(std::time::SystemTime::UNIX_EPOCH)
+        .unwrap();
+    fs::write(&created, "b").unwrap();
+    let mut changed = Snapshot::take(&paths).changed_since(&before);
+    changed.sort();
+    assert_eq!(changed, [created, edited]);
+}
+
+#[test]
+fn diffs_answers() {
+    let first = answers(&[("example", 1, "26"), ("input", 1, "330")]);
+    assert_eq!(
+        watch::render_diff(None, &first),
+        "example part 1: 26\ninput part 1: 330\n"
+    );
+    let second = answers(&[("example", 1, "26"), ("example", 2, "61229")]);
+    assert_eq!(
+        watch::render_diff(Some(&first), &second),
+        "example part 1: 26 (unchanged)\nexample part 2: 61229 (new)\ninput part 1: no answer anymore\n"
+    );
+    let third = answers(&[("example", 1, "27"), ("example", 2, "61229")]);
+    assert!(watch::render_diff(Some(&second), &third).starts_with("example part 1: 27 (was 26)\n"));
+}